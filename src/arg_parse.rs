@@ -10,30 +10,70 @@
 /*                                                                            */
 /* ************************************************************************** */
 
+/// Each level of nested calls needs its share of the evaluation stack.
+const MAX_DEPTH: usize = 10_000;
+
 enum Arg {
     Flag(fn(&mut Param)),
+    Valued(fn(&mut Param, String) -> bool),
+    File(String),
     Invalid(String),
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Output {
+    Text,
+    Json,
+}
+
 pub struct Param {
     bin_path: String,
     verbose: bool,
     bench: bool,
+    explain: bool,
     run: bool,
+    fail_fast: bool,
+    files: Vec<String>,
+    evals: Vec<String>,
+    output: Output,
+    precision: Option<usize>,
+    max_depth: usize,
+    tolerance: f64,
+    max_iter: usize,
 }
 
 impl Param {
+    #[allow(clippy::needless_return)]
     pub fn new(argc: usize, argv: Vec<String>) -> Option<Self> {
         let mut param = Param {
             bin_path: argv[0].clone(),
-            verbose: false,
-            bench: false,
-            run: true,
+            ..Param::default()
         };
         let mut i: usize = 1;
         while i < argc {
             match parse_arg(&argv[i]) {
                 Arg::Flag(fun) => fun(&mut param),
+                Arg::Valued(fun) if i + 1 < argc => {
+                    i += 1;
+                    if !fun(&mut param, argv[i].clone()) {
+                        eprintln!(
+                            "[err-arg] - invalid value for argument '{}': '{}'",
+                            argv[i - 1],
+                            argv[i]
+                        );
+                        usage(&mut param);
+                        return None;
+                    }
+                }
+                Arg::Valued(_) => {
+                    eprintln!(
+                        "[err-arg] - missing value after argument: '{}'",
+                        argv[i]
+                    );
+                    usage(&mut param);
+                    return None;
+                }
+                Arg::File(path) => param.files.push(path),
                 Arg::Invalid(inv_arg) => {
                     eprintln!(
                         "[err-arg] - invalid argument detected: '{}'",
@@ -56,9 +96,65 @@ impl Param {
         self.bench
     }
 
+    pub fn explain(&self) -> bool {
+        self.explain
+    }
+
     pub fn run(&self) -> bool {
         self.run
     }
+
+    pub fn fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    pub fn files(&self) -> &Vec<String> {
+        &self.files
+    }
+
+    pub fn evals(&self) -> &Vec<String> {
+        &self.evals
+    }
+
+    pub fn output(&self) -> Output {
+        self.output
+    }
+
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    pub fn max_iter(&self) -> usize {
+        self.max_iter
+    }
+}
+
+impl Default for Param {
+    fn default() -> Self {
+        Param {
+            bin_path: String::from("computorv2"),
+            verbose: false,
+            bench: false,
+            explain: false,
+            run: true,
+            fail_fast: false,
+            files: Vec::new(),
+            evals: Vec::new(),
+            output: Output::Text,
+            precision: None,
+            max_depth: 256,
+            tolerance: 1e-12,
+            max_iter: 500,
+        }
+    }
 }
 
 fn parse_arg(arg: &String) -> Arg {
@@ -66,7 +162,19 @@ fn parse_arg(arg: &String) -> Arg {
         ref str if str == "-h" || str == "--help" => Arg::Flag(usage),
         ref str if str == "-v" || str == "--verbose" => Arg::Flag(set_verbose),
         ref str if str == "-b" || str == "--benchmark" => Arg::Flag(set_bench),
-        _ => Arg::Invalid(arg.clone()),
+        ref str if str == "-x" || str == "--explain" => Arg::Flag(set_explain),
+        ref str if str == "-e" || str == "--eval" => Arg::Valued(add_eval),
+        ref str if str == "-o" || str == "--output" => Arg::Valued(set_output),
+        ref str if str == "-p" || str == "--precision" => {
+            Arg::Valued(set_precision)
+        }
+        ref str if str == "--max-depth" => Arg::Valued(set_max_depth),
+        ref str if str == "--tolerance" => Arg::Valued(set_tolerance),
+        ref str if str == "--max-iter" => Arg::Valued(set_max_iter),
+        ref str if str == "--keep-going" => Arg::Flag(set_keep_going),
+        ref str if str == "--fail-fast" => Arg::Flag(set_fail_fast),
+        ref str if str.starts_with('-') => Arg::Invalid(arg.clone()),
+        _ => Arg::File(arg.clone()),
     }
 }
 
@@ -78,14 +186,91 @@ fn set_bench(param: &mut Param) {
     param.bench = true;
 }
 
+fn set_explain(param: &mut Param) {
+    param.explain = true;
+}
+
+fn add_eval(param: &mut Param, instr: String) -> bool {
+    param.evals.push(instr);
+    true
+}
+
+fn set_output(param: &mut Param, format: String) -> bool {
+    match format.as_str() {
+        "text" => param.output = Output::Text,
+        "json" => param.output = Output::Json,
+        _ => return false,
+    }
+    true
+}
+
+fn set_precision(param: &mut Param, digits: String) -> bool {
+    match digits.parse::<usize>() {
+        Ok(precision) => param.precision = Some(precision),
+        Err(_) => return false,
+    }
+    true
+}
+
+fn set_max_depth(param: &mut Param, depth: String) -> bool {
+    match depth.parse::<usize>() {
+        Ok(max_depth) if max_depth <= MAX_DEPTH => param.max_depth = max_depth,
+        _ => return false,
+    }
+    true
+}
+
+fn set_tolerance(param: &mut Param, value: String) -> bool {
+    match value.parse::<f64>() {
+        Ok(tolerance) if tolerance > 0.0 && tolerance < 1.0 => {
+            param.tolerance = tolerance
+        }
+        _ => return false,
+    }
+    true
+}
+
+fn set_max_iter(param: &mut Param, count: String) -> bool {
+    match count.parse::<usize>() {
+        Ok(max_iter) if max_iter > 0 => param.max_iter = max_iter,
+        _ => return false,
+    }
+    true
+}
+
+fn set_keep_going(param: &mut Param) {
+    param.fail_fast = false;
+}
+
+fn set_fail_fast(param: &mut Param) {
+    param.fail_fast = true;
+}
+
 fn usage(param: &mut Param) {
     println!(
-        "usage: {} {}\n    {}\n    {}\n    {}",
+        "usage: {} [-h | --help] [-v | --verbose] [-b | --bench] [-x | --explain]
+        [-e | --eval <instruction>]... [-o | --output <text | json>]
+        [-p | --precision <digits>] [--max-depth <calls>]
+        [--tolerance <value>] [--max-iter <count>]
+        [--keep-going | --fail-fast] [file ...]
+    -h, --help: Show this message.
+    -v, --verbose: Show additionnal informations per stage of execution.
+    -b, --benchmark: Show duration of each stage of execution.
+    -x, --explain: Show each step taken to solve an equation.
+    -e, --eval: Execute the instruction, can be repeated.
+    -o, --output: Print results as coloured text (default) or as one json
+        object per instruction.
+    -p, --precision: Number of decimals printed before a value is cut (10).
+    --max-depth: Maximum number of nested function calls (256, at most
+        10000).
+    --tolerance: Relative precision of numerical roots above degree 4 (1e-12).
+    --max-iter: Iterations allowed to find numerical roots (500).
+    --keep-going: Keep executing a script after an error (default).
+    --fail-fast: Stop executing scripts at the first error.
+    file: Instruction file executed line by line instead of the prompt.
+Instructions and files are executed in order in the same memory, then the
+program exits with a status matching the last error raised.",
         param.bin_path,
-        "[-h | --help] [-v | --verbose] [-b | --bench]",
-        "-h, --help: Show this message.",
-        "-v, --verbose: Show additionnal informations per stage of execution.",
-        "-b, --benchmark: Show duration of each stage of execution."
     );
     param.run = false;
 }
@@ -11,21 +11,29 @@
 /* ************************************************************************** */
 
 mod error;
+mod explain;
+mod outcome;
+mod polynomial;
 mod result;
+mod system;
 
 pub use error::{ComputorError, ErrorKind};
-pub use result::{Computed, ComputorResult, TreeResult};
+pub use outcome::{Approximation, Outcome, Solution};
+pub use result::{Computed, ComputorResult, EvalResult, TreeResult};
+pub use system::{Affine, System};
 
 use crate::arg_parse::Param;
 use crate::lexer::token;
-use crate::memory::{Memory, Value};
+use crate::memory::{builtin, Memory, Value};
 use crate::parser::{TokenTree, TreeBranch};
+use crate::symbolic::{self, Expr};
 use crate::timer::Timer;
-use crate::types::{Imaginary, Matrix, Rational};
+use crate::types::{
+    Complex, Fraction, Imaginary, Matrix, Polynomial, Rational, Surd,
+};
 use Computed as Comp;
 
 use std::collections::HashMap;
-use std::i32::{MAX as I32_MAX, MIN as I32_MIN};
 
 extern crate colored;
 use colored::Colorize;
@@ -34,11 +42,15 @@ type TTree = Box<dyn TokenTree>;
 type Equ = HashMap<i32, Im>;
 type Im = Imaginary;
 type CErr = ComputorError;
+type Root = (Im, Option<Surd>);
 
 pub struct Computor {
     verbose: bool,
     bench: bool,
+    explain: bool,
     memory: Memory,
+    finder: polynomial::RootFinder,
+    log: Vec<String>,
 }
 
 impl Computor {
@@ -46,40 +58,84 @@ impl Computor {
         Computor {
             verbose: param.verbose(),
             bench: param.bench(),
-            memory: Memory::new(),
+            explain: param.explain(),
+            memory: Memory::new(param.max_depth()),
+            finder: polynomial::RootFinder::new(param),
+            log: Vec::new(),
         }
     }
 
-    pub fn update_param(&mut self, verbose: bool, benchmark: bool) {
+    pub fn drain_log(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.log)
+    }
+
+    pub fn update_param(
+        &mut self,
+        verbose: bool,
+        benchmark: bool,
+        explain: bool,
+    ) {
         self.verbose = verbose;
         self.bench = benchmark;
+        self.explain = explain;
     }
 
-    pub fn read_tokens(&mut self, tree: TTree) -> ComputorResult {
+    /// `solve` is set when a `?` ends an equation, which is then solved
+    /// instead of assigning to the variable on its left.
+    pub fn read_tokens(&mut self, tree: TTree, solve: bool) -> EvalResult {
         if self.verbose {
-            println!(
-                "{}",
+            self.log.push(
                 format!(
                     "{} - tree received: {:?}",
                     "[v:Computor]".purple().bold(),
                     tree
                 )
                 .dimmed()
+                .to_string(),
             )
         }
         if !self.bench {
-            self.compute(tree)
+            self.compute(tree, solve)
         } else {
             let display = format!("Computor({})", tree);
-            let _timer = Timer::new(&display[..]);
-            self.compute(tree)
+            let timer = Timer::new(&display[..]);
+            let res = self.compute(tree, solve);
+            self.log.push(timer.top());
+            res
         }
     }
 
-    fn compute(&mut self, mut tree: TTree) -> ComputorResult {
+    pub fn read_system(&mut self, trees: Vec<TTree>) -> EvalResult {
+        if self.verbose {
+            for tree in trees.iter() {
+                self.log.push(
+                    format!(
+                        "{} - system equation received: {:?}",
+                        "[v:Computor]".purple().bold(),
+                        tree
+                    )
+                    .dimmed()
+                    .to_string(),
+                )
+            }
+        }
+        if !self.bench {
+            system::solve(&self.memory, trees)
+        } else {
+            let timer = Timer::new("Computor(system)");
+            let res = system::solve(&self.memory, trees);
+            self.log.push(timer.top());
+            res
+        }
+    }
+
+    #[allow(clippy::needless_return)]
+    fn compute(&mut self, mut tree: TTree, solve: bool) -> EvalResult {
         let n = tree.count(token::count_error);
         if n > 0 {
-            return Err(CErr::invalid_tokens(n));
+            let mut errors: Vec<String> = Vec::new();
+            tree.iter(&mut |tok| token::list_error(tok.as_ref(), &mut errors));
+            return Err(CErr::invalid_tokens(n, errors));
         }
 
         let left: Option<TTree>;
@@ -96,39 +152,92 @@ impl Computor {
             }
         };
         if let (Some(br_left), Some(br_right)) = (left, right) {
-            return self.dual_part(br_left, br_right);
+            return self.dual_part(br_left, br_right, solve);
         };
         return Err(CErr::bad_use_op('='));
     }
 
-    fn single_part(&mut self, tree: TTree) -> ComputorResult {
-        let comp = tree.compute(&mut self.memory, None)?;
+    fn single_part(&mut self, tree: TTree) -> EvalResult {
+        if symbolic::is_diff_call(&tree) {
+            return self.derivative(&tree);
+        }
+        let comp = tree.compute(&self.memory, None)?;
         self.single_part_comp(comp)
     }
 
-    fn single_part_comp(&mut self, comp: Comp) -> ComputorResult {
+    fn derivative(&self, tree: &TTree) -> EvalResult {
+        let args = match Expr::from_tree(tree)? {
+            Expr::Call(_, args) => args,
+            _ => return Err(CErr::diff_alone()),
+        };
+        let (target, var, res) = symbolic::differentiate(&args, &self.memory)?;
+        let res = symbolic::simplify(&res.bind(&self.memory, &var)?);
+        return Ok(Outcome::Derivative(
+            target.label(),
+            var,
+            res.to_tree().to_string(),
+        ));
+    }
+
+    fn single_part_comp(&mut self, comp: Comp) -> EvalResult {
         Ok(match comp {
             Comp::None => return Err(CErr::empty_instr()),
             Comp::Res => self.mem_dump(),
-            Comp::ValMat(mat) => {
-                println!("{}", mat.to_string().replace(" ; ", "\n"))
-            }
-            Comp::ValIm(val) => println!("{}", val),
-            Comp::VarCall(_, val) => println!("{}", val),
+            Comp::ValMat(mat) => Outcome::Value(Value::Mat(mat)),
+            Comp::ValIm(val) => Outcome::Value(Value::Im(val)),
+            Comp::VarCall(_, val) => Outcome::Value(val),
             Comp::VarSet(v) => return Err(CErr::unknown_id(v, true)),
             Comp::FunId(f, param) => {
                 self.single_part_comp(self.memory.solve_fun(f, param)?)?
             }
-            Comp::Equ(_, eq) => self.eq_one_sided(eq)?,
+            Comp::Poly(poly) => expression(poly),
+            Comp::Frac(frac) => match frac.as_poly() {
+                Some(poly) => expression(poly),
+                None => Outcome::Fraction(frac),
+            },
         })
     }
 
-    fn dual_part(&mut self, left: TTree, right: TTree) -> ComputorResult {
-        let comp = left.compute(&mut self.memory, None)?;
-        self.dual_part_comp(comp, right)
+    fn dual_part(
+        &mut self,
+        left: TTree,
+        right: TTree,
+        solve: bool,
+    ) -> EvalResult {
+        let index = left.token().as_any().downcast_ref::<token::IndexTree>();
+        let resolve = right.token().as_any().downcast_ref::<token::Resolve>();
+        if let (Some(index), None, false) = (index, resolve, solve) {
+            return self.set_index(index, right);
+        }
+        let comp = left.compute(&self.memory, None)?;
+        match solve {
+            true => self.solve_part_comp(comp, right),
+            false => self.dual_part_comp(comp, right),
+        }
     }
 
-    fn dual_part_comp(&mut self, left: Comp, right: TTree) -> ComputorResult {
+    fn solve_part_comp(&mut self, left: Comp, right: TTree) -> EvalResult {
+        Ok(match left {
+            Comp::VarCall(_, val) => {
+                let right = right.compute(&self.memory, None)?;
+                match val {
+                    Value::Im(val) => self.left_val(val, right)?,
+                    Value::Mat(mat) => self.dual_matr(mat, right)?,
+                    Value::Poly(poly) => self.eq_two_sided(poly, right)?,
+                }
+            }
+            Comp::VarSet(id) => self.eq_two_sided(
+                Polynomial::unknown(&id),
+                right.compute(&self.memory, None)?,
+            )?,
+            Comp::FunId(id, param) => {
+                self.solve_part_comp(self.memory.solve_fun(id, param)?, right)?
+            }
+            left => self.dual_part_comp(left, right)?,
+        })
+    }
+
+    fn dual_part_comp(&mut self, left: Comp, right: TTree) -> EvalResult {
         Ok(match left {
             Comp::None => return Err(CErr::bad_use_op('=')),
             Comp::Res => return Err(CErr::bad_resolve()),
@@ -145,141 +254,104 @@ impl Computor {
                 self.set_var(id, right.compute(&self.memory, None)?)?
             }
             Comp::FunId(id, param) => self.set_fun(id, param, right)?,
-            Comp::Equ(id, eq) => {
-                self.eq_two_sided(id, eq, right.compute(&self.memory, None)?)?
+            Comp::Poly(poly) => {
+                self.eq_two_sided(poly, right.compute(&self.memory, None)?)?
             }
-        })
-    }
-
-    fn eq_one_sided(&self, eq: Equ) -> ComputorResult {
-        let zero: i32 = 0;
-        for (pow, coef) in eq.iter() {
-            if *pow > 0 && *coef != Im::new(0.0, 0.0) {
-                return Err(CErr::uncomplete_eq());
+            Comp::Frac(frac) => {
+                self.eq_fraction(frac, right.compute(&self.memory, None)?)?
             }
-        }
-        if let Some(coef) = eq.get(&zero) {
-            println!("{}", *coef);
-        } else {
-            return Err(CErr::uncomplete_eq());
-        }
-        Ok(())
+        })
     }
 
-    fn eq_two_sided(
-        &self,
-        id: String,
-        mut left: Equ,
-        right: Comp,
-    ) -> ComputorResult {
+    fn eq_two_sided(&self, left: Polynomial, right: Comp) -> EvalResult {
         Ok(match right {
             Comp::None => return Err(CErr::bad_use_op('=')),
-            Comp::Res => return Err(CErr::uncomplete_eq()),
+            Comp::Res => expression(left),
             Comp::ValMat(_) => return Err(CErr::matrix_in_eq()),
-            Comp::ValIm(val) => {
-                val_into_eq(&mut left, val)?;
-                self.solve_eq(left, id)?;
-            }
-            Comp::VarCall(id_v, val) => match val {
-                Value::Im(val) => {
-                    var_into_eq(&mut left, &id, id_v, val)?;
-                    self.solve_eq(left, id)?;
-                }
-                Value::Mat(_) => return Err(CErr::matrix_in_eq()),
-            },
-            Comp::VarSet(v) => {
-                unknow_into_eq(&mut left, &id, v)?;
-                self.solve_eq(left, id)?;
-            }
-            Comp::FunId(f, arg) => {
-                self.eq_two_sided(id, left, self.memory.solve_fun(f, arg)?)?
-            }
-            Comp::Equ(id_r, eq) => {
-                fuse_eq(&mut left, &id, eq, id_r)?;
-                self.solve_eq(left, id)?;
+            Comp::Frac(frac) => {
+                self.solve_frac(Fraction::from_poly(left), frac)?
             }
+            right => self.solve_poly(left, right.into_poly(&self.memory)?)?,
         })
     }
 
-    fn left_val(&self, val: Im, right: Comp) -> ComputorResult {
+    fn eq_fraction(&self, left: Fraction, right: Comp) -> EvalResult {
         Ok(match right {
             Comp::None => return Err(CErr::bad_use_op('=')),
-            Comp::Res => println!("{}", val),
-            Comp::ValMat(_) => println!("False"),
-            Comp::ValIm(r_val) => solve_two_val(val, Value::Im(r_val)),
-            Comp::VarCall(_, r_val) => solve_two_val(val, r_val),
-            Comp::VarSet(v) => println!("{} = {} is a solution.", v, val),
-            Comp::FunId(f, arg) => {
-                self.left_val(val, self.memory.solve_fun(f, arg)?)?
-            }
-            Comp::Equ(id, eq) => {
-                let mut eq = eq;
-                val_into_eq(&mut eq, val)?;
-                self.solve_eq(eq, id)?;
-            }
+            Comp::Res => Outcome::Fraction(left),
+            Comp::ValMat(_) => return Err(CErr::matrix_in_eq()),
+            right => self.solve_frac(left, right.into_frac(&self.memory)?)?,
         })
     }
 
-    fn call_var(
-        &mut self,
-        var: String,
-        val: Value,
-        right: Comp,
-    ) -> ComputorResult {
+    fn left_val(&self, val: Im, right: Comp) -> EvalResult {
         Ok(match right {
             Comp::None => return Err(CErr::bad_use_op('=')),
-            Comp::Res => println!("{}", val),
-            Comp::ValMat(mat) => {
-                self.memory.set_var(var, Value::Mat(mat));
+            Comp::Res => Outcome::Value(Value::Im(val)),
+            Comp::ValMat(_) => Outcome::Boolean(false),
+            Comp::ValIm(r_val) => solve_two_val(val, Value::Im(r_val)),
+            Comp::VarCall(_, Value::Im(r_val)) => {
+                solve_two_val(val, Value::Im(r_val))
             }
-            Comp::ValIm(nval) => {
-                self.memory.set_var(var, Value::Im(nval));
+            Comp::VarCall(_, Value::Mat(_)) => Outcome::Boolean(false),
+            Comp::FunId(f, arg) => {
+                self.left_val(val, self.memory.solve_fun(f, arg)?)?
             }
-            Comp::VarCall(_, nval) => {
-                self.memory.set_var(var, nval);
+            Comp::Frac(frac) => {
+                let left = Fraction::from_poly(Polynomial::constant(val));
+                self.solve_frac(left, frac)?
             }
-            Comp::VarSet(v) => return Err(CErr::unknown_id(v, true)),
-            Comp::FunId(f, arg) => {
-                self.call_var(var, val, self.memory.solve_fun(f, arg)?)?
+            right @ (Comp::VarSet(_)
+            | Comp::Poly(_)
+            | Comp::VarCall(_, Value::Poly(_))) => {
+                let left = Polynomial::constant(val);
+                self.solve_poly(left, right.into_poly(&self.memory)?)?
             }
-            Comp::Equ(id, eq) => match val {
-                Value::Im(val) => {
-                    let mut eq = eq;
-                    var_into_eq(&mut eq, &id, var, val)?;
-                    self.solve_eq(eq, id)?;
-                }
-                Value::Mat(_) => return Err(CErr::matrix_in_eq()),
-            },
         })
     }
 
-    fn set_var(&mut self, var: String, right: Comp) -> ComputorResult {
+    fn call_var(&mut self, var: String, val: Value, right: Comp) -> EvalResult {
+        match right {
+            Comp::Res => Ok(Outcome::Value(val)),
+            right => self.set_var(var, right),
+        }
+    }
+
+    fn set_var(&mut self, var: String, right: Comp) -> EvalResult {
         Ok(match right {
             Comp::None => return Err(CErr::bad_use_op('=')),
             Comp::Res => return Err(CErr::unknown_id(var, true)),
-            Comp::ValMat(mat) => {
-                self.memory.set_var(var, Value::Mat(mat));
-            }
-            Comp::ValIm(val) => {
-                self.memory.set_var(var, Value::Im(val));
-            }
-            Comp::VarCall(_, val) => {
-                self.memory.set_var(var, val);
-            }
+            Comp::ValMat(mat) => self.memory.set_var(var, Value::Mat(mat)),
+            Comp::ValIm(val) => self.memory.set_var(var, Value::Im(val)),
+            Comp::VarCall(_, val) => self.memory.set_var(var, val),
             Comp::VarSet(id) => {
                 if id != var {
-                    return Err(CErr::unknown_id(id, true));
+                    let poly = Polynomial::unknown(&id);
+                    self.memory.set_var(var, Value::Poly(poly))
                 } else {
-                    println!("Any value for {} is a solution.", id);
+                    let poly = Polynomial::unknown(&id);
+                    self.solve_poly(poly.clone(), poly)?
                 }
             }
             Comp::FunId(f, arg) => {
                 self.set_var(var, self.memory.solve_fun(f, arg)?)?
             }
-            Comp::Equ(id, eq) => {
-                let mut eq = eq;
-                unknow_into_eq(&mut eq, &id, var)?;
-                self.solve_eq(eq, id)?;
+            Comp::Poly(poly) => {
+                if poly.unknowns().contains(&var) {
+                    self.solve_poly(Polynomial::unknown(&var), poly)?
+                } else {
+                    self.memory.set_var(var, Value::Poly(poly))
+                }
+            }
+            Comp::Frac(frac) => {
+                let unknowns = frac.num().unknowns().iter();
+                if unknowns.chain(frac.den().unknowns()).any(|id| *id == var) {
+                    let left = Fraction::from_poly(Polynomial::unknown(&var));
+                    self.solve_frac(left, frac)?
+                } else {
+                    let poly = Comp::Frac(frac).into_poly(&self.memory)?;
+                    self.set_var(var, Comp::from_poly(poly))?
+                }
             }
         })
     }
@@ -289,234 +361,324 @@ impl Computor {
         id: String,
         param: Vec<Comp>,
         exp: TTree,
-    ) -> ComputorResult {
+    ) -> EvalResult {
         match exp.token().as_any().downcast_ref::<token::Resolve>() {
-            None => {
-                self.memory.set_fun(id, param, exp)?;
-                Ok(())
-            }
+            None => self.memory.set_fun(id, param, exp),
             Some(_) => self.set_or_print_fn(id, param),
         }
     }
 
-    fn dual_matr(&self, mat: Matrix, right: Comp) -> ComputorResult {
+    fn dual_matr(&self, mat: Matrix, right: Comp) -> EvalResult {
         Ok(match right {
             Comp::None => return Err(CErr::bad_use_op('=')),
-            Comp::Res => println!("{}", mat.to_string().replace(" ; ", "\n")),
-            Comp::ValMat(other) => {
-                println!("{}", if mat == other { "True" } else { "False" });
-            }
-            Comp::ValIm(_) => println!("False"),
+            Comp::Res => Outcome::Value(Value::Mat(mat)),
+            Comp::ValMat(other) => Outcome::Boolean(mat == other),
+            Comp::ValIm(_) => Outcome::Boolean(false),
             Comp::VarCall(_, val) => match val {
-                Value::Im(_) => println!("False"),
-                Value::Mat(val) => {
-                    println!("{}", if mat == val { "True" } else { "False" })
-                }
+                Value::Im(_) => Outcome::Boolean(false),
+                Value::Mat(val) => Outcome::Boolean(mat == val),
+                Value::Poly(_) => return Err(CErr::matrix_in_eq()),
             },
             Comp::VarSet(v) => return Err(CErr::unknown_id(v, true)),
             Comp::FunId(f, arg) => {
                 self.dual_matr(mat, self.memory.solve_fun(f, arg)?)?
             }
-            Comp::Equ(_, _) => return Err(CErr::matrix_in_eq()),
+            Comp::Poly(_) | Comp::Frac(_) => return Err(CErr::matrix_in_eq()),
         })
     }
 
-    fn set_or_print_fn(
+    fn set_index(
         &mut self,
-        id: String,
-        param: Vec<Comp>,
-    ) -> ComputorResult {
+        index: &token::IndexTree,
+        right: TTree,
+    ) -> EvalResult {
+        let id = match index.id() {
+            Some(id) => id.clone(),
+            None => return Err(CErr::not_matrix(&index.to_string())),
+        };
+        let mat = match self.memory.get_var(&id).map(|var| var.val()) {
+            Some(Value::Mat(mat)) => mat,
+            Some(_) => return Err(CErr::not_matrix(&id)),
+            None => return Err(CErr::unknown_id(id, true)),
+        };
+        let (row, col) = index.indices(&self.memory, None, &mat)?;
+        let val = self.matrix_val(right.compute(&self.memory, None)?)?;
+        let res = mat.assign(row, col, &val)?;
+        Ok(self.memory.set_var(id, Value::Mat(res)))
+    }
+
+    fn matrix_val(&self, comp: Comp) -> Result<Matrix, CErr> {
+        let val = match comp {
+            Comp::ValMat(mat) | Comp::VarCall(_, Value::Mat(mat)) => {
+                return Ok(mat)
+            }
+            Comp::ValIm(val) | Comp::VarCall(_, Value::Im(val)) => val,
+            Comp::FunId(f, arg) => {
+                return self.matrix_val(self.memory.solve_fun(f, arg)?)
+            }
+            _ => return Err(CErr::matrix_val()),
+        };
+        let mut mat = Matrix::new(1, 1);
+        mat.push(val);
+        Ok(mat)
+    }
+
+    fn set_or_print_fn(&mut self, id: String, param: Vec<Comp>) -> EvalResult {
+        if !self.memory.is_fun(&id) {
+            return Err(CErr::unknown_id(id, false));
+        }
         match self.memory.get_fun(&id) {
-            None => Err(CErr::unknown_id(id, false)),
+            None if id == builtin::MAP || self.memory.valid_args(&param) => {
+                self.single_part_comp(self.memory.solve_fun(id, param)?)
+            }
+            None => Err(CErr::fun_arg_inv(&id)),
             Some(fun) => {
                 if self.memory.valid_args(&param) {
-                    self.single_part_comp(self.memory.solve_fun(id, param)?)?;
+                    self.single_part_comp(self.memory.solve_fun(id, param)?)
                 } else {
-                    fun.print(self.memory.param_to_string(param)?);
+                    let alias = self.memory.param_to_string(param)?;
+                    match fun.print(alias) {
+                        Some(def) => Ok(def),
+                        None => Err(CErr::fun_arg_inv(&id)),
+                    }
                 }
-                Ok(())
             }
         }
     }
 
-    fn mem_dump(&self) {
-        println!("{}", self.memory);
+    fn mem_dump(&self) -> Outcome {
+        self.memory.dump()
     }
 
-    fn solve_eq(&self, mut eq: Equ, id: String) -> ComputorResult {
-        filter_eq(&mut eq);
-        if !valid_eq(&eq) {
-            return Ok(());
-        }
-        match eq.keys().max() {
-            None => println!("Any value for {} is a solution.", id),
-            Some(max) => match *max {
-                0 => println!("False."),
-                1 => eq_degree_one(eq, id)?,
-                2 => eq_degree_two(eq, id, self.verbose)?,
-                2..=I32_MAX => {
-                    println!("Can't solve equation with degree above 2.")
-                }
-                I32_MIN..=0 => {
-                    println!("Can't solve equation with degree below 0.")
-                }
-            },
+    fn solve_poly(&self, left: Polynomial, right: Polynomial) -> EvalResult {
+        self.solve_cleared(left, right, Vec::new(), Vec::new())
+    }
+
+    fn solve_frac(&self, left: Fraction, right: Fraction) -> EvalResult {
+        let (l_den, r_den) = (left.den(), right.den());
+        let crossed =
+            l_den.as_constant().is_none() || r_den.as_constant().is_none();
+        let steps = match self.explain && crossed {
+            true => explain::cross(&left, &right),
+            false => Vec::new(),
         };
-        Ok(())
+        let (l_mul, r_mul) = match l_den.gcd(r_den) {
+            Ok(common) => (r_den.divrem(&common)?.0, l_den.divrem(&common)?.0),
+            Err(_) => (r_den.clone(), l_den.clone()),
+        };
+        let mut dens = vec![l_den.clone(), r_den.clone()];
+        dens.extend_from_slice(left.poles());
+        dens.extend_from_slice(right.poles());
+        self.solve_cleared(
+            left.num().mul(&l_mul)?,
+            right.num().mul(&r_mul)?,
+            dens,
+            steps,
+        )
     }
-}
 
-fn val_into_eq(eq: &mut Equ, val: Im) -> ComputorResult {
-    let zero: i32 = 0;
-    match eq.get_mut(&zero) {
-        None => {
-            eq.insert(zero, -val);
+    fn solve_cleared(
+        &self,
+        left: Polynomial,
+        right: Polynomial,
+        mut dens: Vec<Polynomial>,
+        steps: Vec<String>,
+    ) -> EvalResult {
+        let eq = left.sub(&right)?;
+        let mut unknowns = eq.unknowns().iter();
+        match (unknowns.next(), unknowns.next()) {
+            (None, _) => Ok(Outcome::Boolean(eq.is_zero())),
+            (Some(id), None) => match eq.univariate(id) {
+                Some(reduced) => {
+                    let steps = match self.explain {
+                        true => explain::reduce(&left, &right, id, steps)?,
+                        false => steps,
+                    };
+                    if has_pole(&left, id) || has_pole(&right, id) {
+                        dens.push(Polynomial::unknown(id));
+                    }
+                    self.solve_eq(reduced, id.clone(), steps, dens)
+                }
+                None => Err(CErr::too_many_unknown()),
+            },
+            _ if eq.linear().is_none() => Err(CErr::too_many_unknown()),
+            _ => system::solve_equations(vec![eq]),
         }
-        Some(coef) => *coef = coef.sub(&val)?,
-    };
-    Ok(())
-}
+    }
 
-fn var_into_eq(
-    eq: &mut Equ,
-    id: &String,
-    id_v: String,
-    val: Im,
-) -> ComputorResult {
-    let pow: i32;
-    let n_coef: Im;
-    if *id == id_v {
-        pow = 1;
-        n_coef = Im::new(1.0, 0.0);
-    } else {
-        pow = 0;
-        n_coef = val;
-    };
-    match eq.get_mut(&pow) {
-        None => {
-            eq.insert(pow, -n_coef);
+    fn exclude(
+        &self,
+        sol: &mut Solution,
+        dens: &[Polynomial],
+    ) -> ComputorResult {
+        let zero = Polynomial::constant(Im::new(0.0, 0.0));
+        let id = sol.id.clone();
+        let in_id = |den: &&Polynomial| {
+            den.as_constant().is_none()
+                && den.unknowns().iter().all(|u| *u == id)
+        };
+        for den in dens.iter().filter(in_id) {
+            if !sol.reduced.is_empty() {
+                continue;
+            }
+            if let Outcome::EquationSolved(poles) =
+                self.solve_poly(den.clone(), zero.clone())?
+            {
+                for pole in poles.roots {
+                    if !sol.excluded.contains(&pole) {
+                        sol.excluded.push(pole);
+                    }
+                }
+            }
         }
-        Some(coef) => *coef = coef.sub(&n_coef)?,
-    };
-    Ok(())
-}
-
-fn unknow_into_eq(eq: &mut Equ, id: &String, unk: String) -> ComputorResult {
-    if *id == unk {
-        let one: i32 = 1;
-        let one_im = Im::new(1.0, 0.0);
-        match eq.get_mut(&one) {
-            None => {
-                eq.insert(one, -one_im);
+        let mut pos = 0;
+        while pos < sol.roots.len() {
+            let root = &sol.roots[pos];
+            let mut excluded = false;
+            for den in dens.iter() {
+                excluded = excluded || vanishes(den, &sol.id, root)?;
+            }
+            if !excluded {
+                pos += 1;
+                continue;
+            }
+            sol.excluded.push(sol.roots.remove(pos));
+            if pos < sol.surds.len() {
+                sol.surds.remove(pos);
+            }
+            if let Some(approx) = &mut sol.approximation {
+                approx.multiplicities.remove(pos);
             }
-            Some(coef) => *coef = coef.sub(&one_im)?,
         }
         Ok(())
-    } else {
-        Err(CErr::too_many_unknown())
     }
-}
 
-fn fuse_eq(
-    eq_l: &mut Equ,
-    id_l: &String,
-    eq_r: Equ,
-    id_r: String,
-) -> ComputorResult {
-    if *id_l == id_r {
-        for (pow, val) in eq_r {
-            match eq_l.get_mut(&pow) {
-                None => {
-                    eq_l.insert(pow, -val);
-                }
-                Some(coef) => *coef = coef.sub(&val)?,
-            };
+    fn solve_eq(
+        &self,
+        mut eq: Equ,
+        id: String,
+        mut steps: Vec<String>,
+        dens: Vec<Polynomial>,
+    ) -> EvalResult {
+        filter_eq(&mut eq);
+        let (eq, shift) = clear_denominators(eq);
+        if self.explain && shift > 0 {
+            steps.push(explain::multiply(&eq, &id, shift));
         }
-        Ok(())
-    } else {
-        Err(CErr::too_many_unknown())
+        let degree = match eq.keys().max() {
+            None => 0,
+            Some(max) => *max,
+        };
+        let mut sol = Solution::new(id, degree, eq);
+        match degree {
+            0 => {}
+            1 => eq_degree_one(&mut sol)?,
+            2 => eq_degree_two(&mut sol)?,
+            3 => polynomial::eq_degree_three(&mut sol)?,
+            4 => polynomial::eq_degree_four(&mut sol)?,
+            _ => self.finder.solve(&mut sol)?,
+        };
+        if self.explain {
+            steps.extend(explain::solve(&sol));
+        }
+        self.exclude(&mut sol, &dens)?;
+        if self.explain {
+            steps.extend(explain::reject(&sol));
+            sol.steps = steps;
+        }
+        Ok(Outcome::EquationSolved(sol))
     }
 }
 
-fn solve_two_val(val_l: Im, val_r: Value) {
+fn expression(poly: Polynomial) -> Outcome {
+    match poly.as_constant() {
+        Some(val) => Outcome::Value(Value::Im(val)),
+        None => Outcome::Expression(poly),
+    }
+}
+
+fn solve_two_val(val_l: Im, val_r: Value) -> Outcome {
     match val_r {
-        Value::Im(val_r) => {
-            if val_l == val_r {
-                println!("True");
-            } else {
-                println!("False");
-            }
-        }
-        Value::Mat(_) => println!("False"),
+        Value::Im(val_r) => Outcome::Boolean(val_l == val_r),
+        Value::Mat(_) | Value::Poly(_) => Outcome::Boolean(false),
     }
 }
 
-fn eq_degree_one(eq: Equ, id: String) -> ComputorResult {
-    let mut index: i32 = 0;
-    let zero = match eq.get(&index) {
+fn get_coef(eq: &Equ, pow: i32) -> Im {
+    match eq.get(&pow) {
         None => Im::new(0.0, 0.0),
-        Some(val) => *val,
-    };
-    index += 1;
-    let one = *eq.get(&index).unwrap();
-    print_eq(&eq, &id, 1);
-    println!("Solution: {} = {}", id, -zero.div(&one)?);
+        Some(val) => val.clone(),
+    }
+}
+
+fn eq_degree_one(sol: &mut Solution) -> ComputorResult {
+    let zero = get_coef(&sol.reduced, 0);
+    let one = get_coef(&sol.reduced, 1);
+    sol.roots.push(-zero.div(&one)?);
     Ok(())
 }
 
-fn eq_degree_two(eq: Equ, id: String, verb: bool) -> ComputorResult {
-    let mut index: i32 = 0;
-    let deg_zero = match eq.get(&index) {
-        None => Imaginary::new(0.0, 0.0),
-        Some(val) => *val,
-    };
-    index += 1;
-    let deg_one = match eq.get(&index) {
-        None => Imaginary::new(0.0, 0.0),
-        Some(val) => *val,
-    };
-    index += 1;
-    let deg_two = *eq.get(&index).unwrap();
-    print_eq(&eq, &id, 2);
-    let right = Im::new(4.0, 0.0).mul(&deg_two)?.mul(&deg_zero)?;
-    let delta = deg_one.pow(2)?.sub(&right)?.get_real();
-    if verb {
-        println!(
-            "{}",
-            format!(
-                "{} - Delta = {}",
-                "[v:Computor]".purple().bold(),
-                delta.to_string()
-            )
-            .dimmed()
-        );
-    }
+fn eq_degree_two(sol: &mut Solution) -> ComputorResult {
+    let (delta, roots) = quadratic(
+        &get_coef(&sol.reduced, 0),
+        &get_coef(&sol.reduced, 1),
+        &get_coef(&sol.reduced, 2),
+    )?;
+    (sol.roots, sol.surds) = roots.into_iter().unzip();
+    sol.discriminant = Some(delta);
+    Ok(())
+}
+
+fn quadratic(
+    deg_zero: &Im,
+    deg_one: &Im,
+    deg_two: &Im,
+) -> Result<(Im, Vec<Root>), CErr> {
+    let mut roots: Vec<Root> = Vec::new();
+    let right = Im::new(4.0, 0.0).mul(deg_two)?.mul(deg_zero)?;
+    let delta = deg_one.pow(2)?.sub(&right)?;
     let two_re = Im::new(2.0, 0.0);
     let div = deg_two.mul(&two_re)?;
-    if delta > Rational::zero() {
-        let root = Im::new((delta.get_val()).sqrt(), 0.0);
-        let sol_a = (-deg_one).add(&root)?.div(&div)?;
-        let sol_b = (-deg_one).sub(&root)?.div(&div)?;
-        println!(
-            "Delta is positive, 2 real solutions:\n{} = {}\n{} = {}",
-            id, sol_a, id, sol_b
-        );
-    } else if delta < Rational::zero() {
-        let root = Im::new(0.0, (-delta.get_val()).sqrt());
-        let sol_a = (-deg_one).add(&root)?.div(&div)?;
-        let sol_b = (-deg_one).sub(&root)?.div(&div)?;
-        println!(
-            "Delta is negative, 2 imaginary solutions:\n{} = {}\n{} = {}",
-            id, sol_a, id, sol_b
-        );
+    if delta != Im::new(0.0, 0.0) {
+        let root = match builtin::exact_sqrt(&delta) {
+            Some(root) => root,
+            None => match Complex::from_im(&delta).sqrt().to_im() {
+                Some(root) => root,
+                None => return Err(CErr::unsolvable_eq("delta out of range")),
+            },
+        };
+        let surd = surd_roots(&delta, deg_one, &div)?;
+        let (plus, minus) = match surd {
+            Some(surd) => (Some(surd.conjugate()), Some(surd)),
+            None => (None, None),
+        };
+        roots.push(((-deg_one.clone()).add(&root)?.div(&div)?, plus));
+        roots.push(((-deg_one.clone()).sub(&root)?.div(&div)?, minus));
     } else {
-        let sol = -deg_one.div(&div)?;
-        println!("Delta is null, 1 real solution:\n{} = {}", id, sol);
+        roots.push((-deg_one.div(&div)?, None));
     }
-    Ok(())
+    Ok((delta, roots))
+}
+
+fn surd_roots(
+    delta: &Im,
+    deg_one: &Im,
+    div: &Im,
+) -> Result<Option<Surd>, CErr> {
+    if !(delta.is_real() && deg_one.is_real() && div.is_real()) {
+        return Ok(None);
+    }
+    let surd = match Surd::sqrt(&delta.get_real()) {
+        Some(surd) => surd,
+        None => return Ok(None),
+    };
+    let one = Rational::new(1.0);
+    let scale = -one.div(&div.get_real())?;
+    let shift = deg_one.get_real().mul(&scale)?;
+    Ok(Some(surd.affine(&scale, &shift)?))
 }
 
-pub fn filter_eq(eq: &mut Equ) {
+fn filter_eq(eq: &mut Equ) {
     let zero = Im::new(0.0, 0.0);
     let mut dead_key: Vec<i32> = Vec::new();
     for (pow, coef) in eq.iter() {
@@ -529,49 +691,149 @@ pub fn filter_eq(eq: &mut Equ) {
     }
 }
 
-fn valid_eq(eq: &Equ) -> bool {
-    for (pow, coef) in eq.iter() {
-        if *pow < 0 {
-            println!("Can't compute equation with negative pow.");
-            return false;
+fn clear_denominators(eq: Equ) -> (Equ, i32) {
+    let shift = match eq.keys().min() {
+        Some(low) if *low < 0 => -low,
+        _ => return (eq, 0),
+    };
+    let eq = eq
+        .into_iter()
+        .map(|(pow, coef)| (pow + shift, coef))
+        .collect();
+    (eq, shift)
+}
+
+fn has_pole(poly: &Polynomial, id: &str) -> bool {
+    match poly.univariate(id) {
+        Some(eq) => eq.keys().any(|pow| *pow < 0),
+        None => false,
+    }
+}
+
+fn vanishes(den: &Polynomial, id: &str, root: &Im) -> Result<bool, CErr> {
+    let val = den.substitute(id, &Polynomial::constant(root.clone()))?;
+    Ok(val.as_constant() == Some(Im::new(0.0, 0.0)))
+}
+
+#[cfg(test)]
+mod functions {
+    use super::{ErrorKind, Outcome};
+    use crate::arg_parse::Param;
+    use crate::memory::Value;
+    use crate::session::Session;
+    use crate::types::Imaginary as Im;
+
+    fn session(max_depth: &str) -> Session {
+        let argv = ["computorv2", "--max-depth", max_depth];
+        let argv: Vec<String> =
+            argv.iter().map(|arg| arg.to_string()).collect();
+        Session::with_param(&Param::new(argv.len(), argv).unwrap())
+    }
+
+    #[test]
+    fn nested_calls() {
+        let mut session = session("16");
+        session.eval("f(x) = x + 1").unwrap();
+        session.eval("g(x) = 2 * f(x)").unwrap();
+        session.eval("h(x) = g(f(x)) - f(g(x))").unwrap();
+
+        match session.eval("h(3) + f(f(f(1)))").unwrap() {
+            Outcome::Value(Value::Im(val)) => {
+                assert_eq!(val, Im::new(5.0, 0.0))
+            }
+            _ => panic!("not computed as a value"),
         }
-        if !coef.is_real() {
-            println!("Can't compute equation with complex coeficient.");
-            return false;
+    }
+
+    #[test]
+    fn recursion_depth() {
+        let mut session = session("16");
+        session.eval("f(x) = f(x - 1) + 1").unwrap();
+
+        match session.eval("f(3)") {
+            Err(err) => assert_eq!(*err.kind(), ErrorKind::FunDepth),
+            Ok(_) => panic!("recursion not stopped"),
         }
     }
-    return true;
 }
 
-fn print_eq(eq: &Equ, id: &String, degree: i32) {
-    let mut pow: i32 = degree;
-    let mut to_print = eq.len();
-    println!("Equation of degree {}:", degree);
-    while to_print > 0 {
-        if let Some(coef) = eq.get(&pow) {
-            to_print -= 1;
-            let val = *coef;
-            print!(
-                "{}{}{}{}",
-                if val.get_real().get_val() < 0.0 || pow == degree {
-                    " "
-                } else {
-                    " + "
-                },
-                val,
-                if pow != 0 {
-                    format!(" * {}", id)
-                } else {
-                    String::new()
-                },
-                if pow != 0 && pow != 1 {
-                    format!("^{}", pow)
-                } else {
-                    String::new()
-                },
-            );
+#[cfg(test)]
+mod poles {
+    use super::{Outcome, Solution};
+    use crate::session::Session;
+    use crate::types::Imaginary as Im;
+
+    fn solve(input: &str) -> Solution {
+        match Session::new().eval(input) {
+            Ok(Outcome::EquationSolved(sol)) => sol,
+            _ => panic!("{} not solved as an equation", input),
+        }
+    }
+
+    #[test]
+    fn cleared() {
+        let sol = solve("1/x + x = 2");
+
+        assert_eq!(sol.degree, 2);
+        assert_eq!(sol.roots, vec![Im::new(1.0, 0.0)]);
+        assert!(sol.excluded.is_empty());
+    }
+
+    #[test]
+    fn excluded_root() {
+        let sol = solve("x^2/x = 0");
+
+        assert!(sol.roots.is_empty());
+        assert_eq!(sol.excluded, vec![Im::new(0.0, 0.0)]);
+    }
+
+    #[test]
+    fn any_value() {
+        let sol = solve("x/x = 1");
+
+        assert!(sol.reduced.is_empty());
+        assert_eq!(sol.excluded, vec![Im::new(0.0, 0.0)]);
+    }
+}
+
+#[cfg(test)]
+mod assignment {
+    use super::Outcome;
+    use crate::memory::Value;
+    use crate::session::Session;
+
+    fn assigned(session: &mut Session, input: &str) -> bool {
+        matches!(session.eval(input), Ok(Outcome::Assigned(_, _)))
+    }
+
+    #[test]
+    fn overwrite() {
+        let mut session = Session::new();
+        session.eval("p = x^2 - 4").unwrap();
+
+        assert!(assigned(&mut session, "p = 5"));
+        assert!(assigned(&mut session, "p = y + 1"));
+        assert!(assigned(&mut session, "p = [[1,2]]"));
+        assert!(matches!(
+            session.eval("p"),
+            Ok(Outcome::Value(Value::Mat(_)))
+        ));
+    }
+
+    #[test]
+    fn solve_mark() {
+        let mut session = Session::new();
+        session.eval("p = x^2 - 4").unwrap();
+
+        match session.eval("p = 5 ?") {
+            Ok(Outcome::EquationSolved(sol)) => assert_eq!(sol.roots.len(), 2),
+            _ => panic!("not solved as an equation"),
         }
-        pow -= 1;
+        assert!(matches!(session.eval("p = ?"), Ok(Outcome::Value(_))));
+        session.eval("a = 2").unwrap();
+        assert!(matches!(
+            session.eval("a = 2 ?"),
+            Ok(Outcome::Boolean(true))
+        ));
     }
-    println!(" = 0");
 }
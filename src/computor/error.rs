@@ -27,10 +27,13 @@ pub enum ErrorKind {
     EmptyInstr,
     FunUndefinded,
     FunArgInv,
-    FunCallFun,
+    FunDepth,
+    FunBuiltin,
+    FunDomain,
     MatrixInEq,
     MatrixVal,
     MatrixDim,
+    MatrixIndex,
     ModWithIm,
     ModWithUnk,
     InvalidInput,
@@ -39,11 +42,15 @@ pub enum ErrorKind {
     IOStop,
     OpMatrix,
     OverflowAbort,
+    Singular,
     TooManyEqual,
     TooManyUnknown,
     UnparsedToken,
     UncompleteEq,
     UnknownId,
+    UnsolvableEq,
+    Symbolic,
+    NoConvergence,
 }
 
 impl fmt::Display for ErrorKind {
@@ -58,10 +65,13 @@ impl fmt::Display for ErrorKind {
             ErrorKind::EmptyInstr => write!(f, "parser"),
             ErrorKind::FunUndefinded => write!(f, "function"),
             ErrorKind::FunArgInv => write!(f, "function"),
-            ErrorKind::FunCallFun => write!(f, "function"),
+            ErrorKind::FunDepth => write!(f, "function"),
+            ErrorKind::FunBuiltin => write!(f, "function"),
+            ErrorKind::FunDomain => write!(f, "math"),
             ErrorKind::MatrixInEq => write!(f, "parser"),
             ErrorKind::MatrixVal => write!(f, "parser"),
             ErrorKind::MatrixDim => write!(f, "math"),
+            ErrorKind::MatrixIndex => write!(f, "matrix"),
             ErrorKind::ModWithIm => write!(f, "math"),
             ErrorKind::ModWithUnk => write!(f, "parser"),
             ErrorKind::InvalidInput => write!(f, "syntax"),
@@ -70,11 +80,52 @@ impl fmt::Display for ErrorKind {
             ErrorKind::IOStop => write!(f, "input"),
             ErrorKind::OpMatrix => write!(f, "matrix"),
             ErrorKind::OverflowAbort => write!(f, "limit"),
+            ErrorKind::Singular => write!(f, "math"),
             ErrorKind::TooManyEqual => write!(f, "parser"),
             ErrorKind::TooManyUnknown => write!(f, "instruction"),
             ErrorKind::UnparsedToken => write!(f, "parser"),
             ErrorKind::UncompleteEq => write!(f, "parser"),
             ErrorKind::UnknownId => write!(f, "parser"),
+            ErrorKind::UnsolvableEq => write!(f, "math"),
+            ErrorKind::Symbolic => write!(f, "math"),
+            ErrorKind::NoConvergence => write!(f, "math"),
+        }
+    }
+}
+
+impl ErrorKind {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorKind::InvalidInput | ErrorKind::InvalidTokens => 2,
+            ErrorKind::EmptyInstr
+            | ErrorKind::MatrixInEq
+            | ErrorKind::MatrixVal
+            | ErrorKind::ModWithUnk
+            | ErrorKind::TooManyEqual
+            | ErrorKind::UnparsedToken
+            | ErrorKind::UncompleteEq
+            | ErrorKind::UnknownId => 3,
+            ErrorKind::BadPow
+            | ErrorKind::BadResolve
+            | ErrorKind::BadUseOperator
+            | ErrorKind::BadUseOpMat => 4,
+            ErrorKind::DivByEq
+            | ErrorKind::DivByZero
+            | ErrorKind::MatrixDim
+            | ErrorKind::ModWithIm
+            | ErrorKind::Singular
+            | ErrorKind::UnsolvableEq
+            | ErrorKind::FunDomain
+            | ErrorKind::Symbolic
+            | ErrorKind::NoConvergence => 5,
+            ErrorKind::FunUndefinded
+            | ErrorKind::FunArgInv
+            | ErrorKind::FunDepth
+            | ErrorKind::FunBuiltin => 6,
+            ErrorKind::OpMatrix | ErrorKind::MatrixIndex => 7,
+            ErrorKind::OverflowAbort => 8,
+            ErrorKind::TooManyUnknown => 9,
+            ErrorKind::IO | ErrorKind::IOStop => 10,
         }
     }
 }
@@ -90,6 +141,10 @@ impl ComputorError {
         &self.kind
     }
 
+    pub fn info(&self) -> &String {
+        &self.info
+    }
+
     pub fn bad_pow() -> Self {
         ComputorError {
             kind: ErrorKind::BadPow,
@@ -138,6 +193,34 @@ impl ComputorError {
         }
     }
 
+    pub fn rational_expr() -> Self {
+        ComputorError {
+            kind: ErrorKind::DivByEq,
+            info: String::from(
+                "A rational expression can only be printed or solved.",
+            ),
+        }
+    }
+
+    pub fn not_divisible(rem: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::DivByEq,
+            info: format!(
+                "Division leaves a remainder of {}, use quo() and '%'.",
+                rem
+            ),
+        }
+    }
+
+    pub fn neg_pow_division() -> Self {
+        ComputorError {
+            kind: ErrorKind::DivByEq,
+            info: String::from(
+                "Polynomial division can't handle negative powers.",
+            ),
+        }
+    }
+
     pub fn div_by_zero() -> Self {
         ComputorError {
             kind: ErrorKind::DivByZero,
@@ -166,10 +249,30 @@ impl ComputorError {
         }
     }
 
-    pub fn fun_call_fun() -> Self {
+    pub fn fun_depth(max: usize) -> Self {
         ComputorError {
-            kind: ErrorKind::FunCallFun,
-            info: format!("Functions are not allowed to call any function."),
+            kind: ErrorKind::FunDepth,
+            info: format!(
+                "Maximum depth of {} nested function calls reached, abort.",
+                max
+            ),
+        }
+    }
+
+    pub fn fun_builtin(name: &String) -> Self {
+        ComputorError {
+            kind: ErrorKind::FunBuiltin,
+            info: format!(
+                "'{}' is a built-in function, it can't be redefined.",
+                name
+            ),
+        }
+    }
+
+    pub fn fun_domain(name: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::FunDomain,
+            info: format!("Argument outside of the domain of '{}'.", name),
         }
     }
 
@@ -205,12 +308,77 @@ impl ComputorError {
             } else {
                 format!(
                     "Two matrix should have {}.",
-                    "the same dimensions to be added/substracted"
+                    "the same dimensions to be combined cell by cell"
                 )
             },
         }
     }
 
+    pub fn matrix_solve() -> Self {
+        ComputorError {
+            kind: ErrorKind::MatrixDim,
+            info: format!(
+                "Two matrix should have {}.",
+                "as many rows to solve the system"
+            ),
+        }
+    }
+
+    pub fn matrix_slice() -> Self {
+        ComputorError {
+            kind: ErrorKind::MatrixDim,
+            info: String::from(
+                "Assigned value should have the dimensions of the slice.",
+            ),
+        }
+    }
+
+    pub fn matrix_index(index: &str, bound: u32, row: bool) -> Self {
+        let (name, names) = if row {
+            ("Row", "rows")
+        } else {
+            ("Column", "columns")
+        };
+        ComputorError {
+            kind: ErrorKind::MatrixIndex,
+            info: format!(
+                "{} index {} is out of range, the matrix having {} {}.",
+                name, index, bound, names
+            ),
+        }
+    }
+
+    pub fn not_matrix(id: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::MatrixIndex,
+            info: format!("'{}' isn't a matrix, it can't be indexed.", id),
+        }
+    }
+
+    pub fn bad_index(index: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::MatrixIndex,
+            info: format!(
+                "Matrix index should be a positive integer or ':', not {}.",
+                index
+            ),
+        }
+    }
+
+    pub fn matrix_square(op: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::MatrixDim,
+            info: format!("Matrix should be square to compute its {}.", op),
+        }
+    }
+
+    pub fn singular() -> Self {
+        ComputorError {
+            kind: ErrorKind::Singular,
+            info: String::from("Matrix is singular, it has no inverse."),
+        }
+    }
+
     pub fn mod_with_unk() -> Self {
         ComputorError {
             kind: ErrorKind::ModWithUnk,
@@ -229,13 +397,14 @@ impl ComputorError {
         }
     }
 
-    pub fn invalid_tokens(nb: i32) -> Self {
+    pub fn invalid_tokens(nb: i32, errors: Vec<String>) -> Self {
         ComputorError {
             kind: ErrorKind::InvalidTokens,
-            info: format!("{} invalid tokens. Abort.", nb),
+            info: format!("{} invalid tokens: {} Abort.", nb, errors.join(" ")),
         }
     }
 
+    #[allow(clippy::useless_format)]
     pub fn io(cut: &str) -> Self {
         ComputorError {
             kind: ErrorKind::IO,
@@ -285,6 +454,7 @@ impl ComputorError {
         }
     }
 
+    #[allow(clippy::useless_format)]
     pub fn uncomplete_eq() -> Self {
         ComputorError {
             kind: ErrorKind::UncompleteEq,
@@ -292,6 +462,40 @@ impl ComputorError {
         }
     }
 
+    pub fn not_derivable(what: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::Symbolic,
+            info: format!("Can't differentiate {}.", what),
+        }
+    }
+
+    pub fn diff_alone() -> Self {
+        ComputorError {
+            kind: ErrorKind::Symbolic,
+            info: format!(
+                "'diff' must be {} or the body of a function definition.",
+                "a whole instruction"
+            ),
+        }
+    }
+
+    pub fn no_convergence(iterations: usize, tolerance: f64) -> Self {
+        ComputorError {
+            kind: ErrorKind::NoConvergence,
+            info: format!(
+                "Roots did not converge to {:e} in {} iterations.",
+                tolerance, iterations
+            ),
+        }
+    }
+
+    pub fn unsolvable_eq(reason: &str) -> Self {
+        ComputorError {
+            kind: ErrorKind::UnsolvableEq,
+            info: format!("Can't solve equation with {}.", reason),
+        }
+    }
+
     pub fn unknown_id(id: String, is_var: bool) -> Self {
         ComputorError {
             kind: ErrorKind::UnknownId,
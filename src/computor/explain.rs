@@ -0,0 +1,204 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   explain.rs                                         :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{get_coef, ComputorError as CErr, Solution};
+
+use crate::render::print_seq;
+use crate::types::{Fraction, Imaginary as Im, Polynomial, Surd};
+
+use std::collections::{BTreeMap, HashMap};
+
+pub fn cross(left: &Fraction, right: &Fraction) -> Vec<String> {
+    vec![format!("Write the equation: {} = {}", left, right)]
+}
+
+pub fn reduce(
+    left: &Polynomial,
+    right: &Polynomial,
+    id: &str,
+    mut steps: Vec<String>,
+) -> Result<Vec<String>, CErr> {
+    let (left, right) = match (left.univariate(id), right.univariate(id)) {
+        (Some(left), Some(right)) => (left, right),
+        _ => return Ok(Vec::new()),
+    };
+    let sorted = |side: HashMap<i32, Im>| {
+        let mut terms: Vec<(i32, Im)> = side.into_iter().collect();
+        terms.sort_by(|(l, _), (r, _)| r.cmp(l));
+        terms
+    };
+    let (left, right) = (sorted(left), sorted(right));
+    let action = match steps.is_empty() {
+        true => "Write the equation",
+        false => "Clear the denominators",
+    };
+    steps.push(format!(
+        "{}: {} = {}",
+        action,
+        side(&left, id),
+        side(&right, id)
+    ));
+    let mut moved = left;
+    if !right.is_empty() {
+        moved.extend(right.into_iter().map(|(pow, coef)| (pow, -coef)));
+        steps.push(format!(
+            "Move the terms of the right side to the left: {} = 0",
+            side(&moved, id)
+        ));
+    }
+    let mut merged: BTreeMap<i32, Im> = BTreeMap::new();
+    for (pow, coef) in moved.iter() {
+        let sum = match merged.get(pow) {
+            Some(prev) => prev.add(coef)?,
+            None => coef.clone(),
+        };
+        merged.insert(*pow, sum);
+    }
+    let mut merged: Vec<(i32, Im)> = merged.into_iter().rev().collect();
+    if merged.len() < moved.len() {
+        steps.push(format!(
+            "Merge the terms of same power: {} = 0",
+            side(&merged, id)
+        ));
+    }
+    let zero = Im::new(0.0, 0.0);
+    if merged.iter().any(|(_, coef)| *coef == zero) {
+        merged.retain(|(_, coef)| *coef != zero);
+        steps.push(format!("Drop the zero terms: {} = 0", side(&merged, id)));
+    }
+    Ok(steps)
+}
+
+pub fn multiply(eq: &HashMap<i32, Im>, id: &str, shift: i32) -> String {
+    let mut terms: Vec<(i32, Im)> =
+        eq.iter().map(|(pow, coef)| (*pow, coef.clone())).collect();
+    terms.sort_by(|(l, _), (r, _)| r.cmp(l));
+    let factor = match shift {
+        1 => id.to_string(),
+        _ => format!("{}^{}", id, shift),
+    };
+    format!(
+        "Multiply both sides by {}: {} = 0",
+        factor,
+        side(&terms, id)
+    )
+}
+
+pub fn solve(sol: &Solution) -> Vec<String> {
+    let id = &sol.id;
+    let coef = |pow: i32| paren(&get_coef(&sol.reduced, pow));
+    match sol.degree {
+        1 => vec![
+            format!("Isolate {}: {} = - {} / {}", id, id, coef(0), coef(1)),
+            format!("Simplify: {}", roots(sol)),
+        ],
+        2 => {
+            let mut steps = Vec::new();
+            let delta = match &sol.discriminant {
+                Some(delta) => delta,
+                None => return steps,
+            };
+            steps.push(format!(
+                "Compute the discriminant: Delta = b^2 - 4 * a * c \
+                 = {}^2 - 4 * {} * {} = {}",
+                coef(1),
+                coef(2),
+                coef(0),
+                delta
+            ));
+            let zero = Im::new(0.0, 0.0);
+            let minus_b = match get_coef(&sol.reduced, 1) == zero {
+                true => String::from("0"),
+                false => format!("- {}", coef(1)),
+            };
+            if *delta == zero {
+                steps.push(format!(
+                    "Apply the quadratic formula: {} = - b / (2 * a) \
+                     = {} / (2 * {})",
+                    id,
+                    minus_b,
+                    coef(2)
+                ));
+            } else {
+                steps.push(format!(
+                    "Apply the quadratic formula: {} = (- b ± √Delta) / (2 * a) \
+                     = ({} ± √{}) / (2 * {})",
+                    id,
+                    minus_b,
+                    paren(delta),
+                    coef(2)
+                ));
+            }
+            let radical = format!("√{}", paren(delta));
+            let simplified = match delta.is_real() {
+                true => Surd::sqrt(&delta.get_real()),
+                false => None,
+            };
+            if let Some(root) = simplified {
+                if root.to_string() != radical {
+                    steps.push(format!(
+                        "Simplify the root: {} = {}",
+                        radical, root
+                    ));
+                }
+            }
+            steps.push(format!("Simplify: {}", roots(sol)));
+            steps
+        }
+        3 => vec![String::from("Solve in closed form with Cardano's method")],
+        4 => vec![String::from("Solve in closed form with Ferrari's method")],
+        0 => Vec::new(),
+        _ => vec![String::from("Approximate the roots with the Aberth method")],
+    }
+}
+
+pub fn reject(sol: &Solution) -> Vec<String> {
+    sol.excluded
+        .iter()
+        .map(|root| {
+            format!(
+                "Reject {} = {}, which makes a denominator zero",
+                sol.id, root
+            )
+        })
+        .collect()
+}
+
+fn side(terms: &[(i32, Im)], id: &str) -> String {
+    if terms.is_empty() {
+        return String::from("0");
+    }
+    let terms: Vec<(i32, &Im)> =
+        terms.iter().map(|(pow, coef)| (*pow, coef)).collect();
+    print_seq(&terms, id).trim_start().to_string()
+}
+
+fn paren(val: &Im) -> String {
+    if val.is_real() && val.get_real().is_positive() {
+        val.to_string()
+    } else {
+        format!("({})", val)
+    }
+}
+
+fn roots(sol: &Solution) -> String {
+    let roots: Vec<String> = sol
+        .roots
+        .iter()
+        .enumerate()
+        .map(|(pos, root)| match sol.surds.get(pos) {
+            Some(Some(surd)) => format!("{} = {}", sol.id, surd),
+            _ => format!("{} = {}", sol.id, root),
+        })
+        .collect();
+    roots.join(" or ")
+}
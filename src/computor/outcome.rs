@@ -0,0 +1,79 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   outcome.rs                                         :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::system::System;
+use crate::memory::{Value, Variable};
+use crate::render;
+use crate::types::{Fraction, Imaginary, Polynomial, Surd};
+
+use std::collections::HashMap;
+use std::fmt;
+
+pub enum Outcome {
+    Value(Value),
+    Assigned(String, Value),
+    FunctionDefined(String, Vec<String>, String),
+    Derivative(String, String, String),
+    Expression(Polynomial),
+    Fraction(Fraction),
+    Boolean(bool),
+    EquationSolved(Solution),
+    SystemSolved(System),
+    MemoryDump(Vec<Variable>, Vec<String>),
+    Settings(bool, bool, bool),
+}
+
+pub struct Solution {
+    pub id: String,
+    pub degree: i32,
+    pub reduced: HashMap<i32, Imaginary>,
+    pub discriminant: Option<Imaginary>,
+    pub roots: Vec<Imaginary>,
+    /// Exact forms of irrational roots, matching `roots` when not empty.
+    pub surds: Vec<Option<Surd>>,
+    pub approximation: Option<Approximation>,
+    pub excluded: Vec<Imaginary>,
+    pub steps: Vec<String>,
+}
+
+/// How numerical roots were found, `multiplicities` matching `roots`.
+pub struct Approximation {
+    pub multiplicities: Vec<usize>,
+    pub iterations: usize,
+    pub residual: f64,
+}
+
+impl Solution {
+    pub fn new(
+        id: String,
+        degree: i32,
+        reduced: HashMap<i32, Imaginary>,
+    ) -> Self {
+        Solution {
+            id,
+            degree,
+            reduced,
+            discriminant: None,
+            roots: Vec::new(),
+            surds: Vec::new(),
+            approximation: None,
+            excluded: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", render::outcome(self, false))
+    }
+}
@@ -0,0 +1,706 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   polynomial.rs                                      :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{get_coef, quadratic, ComputorError as CErr, ComputorResult};
+use super::{Approximation, Root, Solution};
+
+use crate::arg_parse::Param;
+use crate::types::{Complex, Imaginary as Im, Natural, Rational};
+
+const POLISH_STEPS: usize = 8;
+const MAX_DENOMINATOR: u128 = 1 << 32;
+const REAL_TOLERANCE: f64 = 1e-9;
+
+pub struct RootFinder {
+    tolerance: f64,
+    max_iter: usize,
+}
+
+impl RootFinder {
+    pub fn new(param: &Param) -> Self {
+        RootFinder {
+            tolerance: param.tolerance(),
+            max_iter: param.max_iter(),
+        }
+    }
+
+    pub fn solve(&self, sol: &mut Solution) -> ComputorResult {
+        let poly: Vec<Complex> = (0..=sol.degree)
+            .map(|pow| Complex::from_im(&get_coef(&sol.reduced, pow)))
+            .collect();
+        let zeros = poly.iter().take_while(|coef| coef.norm() == 0.0).count();
+        let (roots, iterations) = self.iterate(&poly[zeros..])?;
+        let mut clusters = cluster(&poly[zeros..], &roots, self.tolerance);
+        if zeros > 0 {
+            clusters.insert(0, (Complex::new(0.0, 0.0), zeros));
+        }
+        let mut residual: f64 = 0.0;
+        let mut found: Vec<(Im, usize)> = Vec::new();
+        for (root, multiplicity) in clusters.into_iter() {
+            let root = polish(&derivative(&poly, multiplicity - 1), root);
+            residual = residual.max(horner(&poly, root).0.norm());
+            found.push((to_root(root)?, multiplicity));
+        }
+        found.sort_by(|(l, _), (r, _)| {
+            let key = |val: &Im| {
+                (
+                    !val.is_real(),
+                    val.get_real().get_val(),
+                    val.get_irreal().get_val(),
+                )
+            };
+            key(l)
+                .partial_cmp(&key(r))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let (roots, multiplicities) = found.into_iter().unzip();
+        sol.roots = roots;
+        sol.approximation = Some(Approximation {
+            multiplicities,
+            iterations,
+            residual,
+        });
+        Ok(())
+    }
+
+    /// Every root of `poly`, its constant coefficient being non zero.
+    fn iterate(&self, poly: &[Complex]) -> Result<(Vec<Complex>, usize), CErr> {
+        let degree = poly.len() - 1;
+        if degree == 0 {
+            return Ok((Vec::new(), 0));
+        }
+        let mut roots = initial_guess(poly);
+        let mut done = vec![false; degree];
+        for iteration in 1..=self.max_iter {
+            for pos in 0..degree {
+                if done[pos] {
+                    continue;
+                }
+                let z = roots[pos];
+                let (value, slope) = horner(poly, z);
+                if value.norm() <= rounding_bound(poly, z) {
+                    done[pos] = true;
+                    continue;
+                }
+                let ratio = value.div(slope);
+                let mut repulsion = Complex::new(0.0, 0.0);
+                for (other, root) in roots.iter().enumerate() {
+                    if other != pos {
+                        let one = Complex::new(1.0, 0.0);
+                        repulsion = repulsion.add(one.div(z.sub(*root)));
+                    }
+                }
+                let one = Complex::new(1.0, 0.0);
+                let step = ratio.div(one.sub(ratio.mul(repulsion)));
+                if !step.re.is_finite() || !step.im.is_finite() {
+                    continue;
+                }
+                roots[pos] = z.sub(step);
+                if step.norm() <= self.tolerance * roots[pos].norm().max(1.0) {
+                    done[pos] = true;
+                }
+            }
+            if done.iter().all(|done| *done) {
+                return Ok((roots, iteration));
+            }
+        }
+        Err(CErr::no_convergence(self.max_iter, self.tolerance))
+    }
+}
+
+pub fn eq_degree_three(sol: &mut Solution) -> ComputorResult {
+    let [d, c, b, a] = coefs::<4>(sol);
+    sol.discriminant = Some(weighted_sum(&[
+        (18, &[&a, &b, &c, &d]),
+        (-4, &[&b, &b, &b, &d]),
+        (1, &[&b, &b, &c, &c]),
+        (-4, &[&a, &c, &c, &c]),
+        (-27, &[&a, &a, &d, &d]),
+    ])?);
+    solve_closed(sol)
+}
+
+pub fn eq_degree_four(sol: &mut Solution) -> ComputorResult {
+    let [e, d, c, b, a] = coefs::<5>(sol);
+    sol.discriminant = Some(weighted_sum(&[
+        (256, &[&a, &a, &a, &e, &e, &e]),
+        (-192, &[&a, &a, &b, &d, &e, &e]),
+        (-128, &[&a, &a, &c, &c, &e, &e]),
+        (144, &[&a, &a, &c, &d, &d, &e]),
+        (-27, &[&a, &a, &d, &d, &d, &d]),
+        (144, &[&a, &b, &b, &c, &e, &e]),
+        (-6, &[&a, &b, &b, &d, &d, &e]),
+        (-80, &[&a, &b, &c, &c, &d, &e]),
+        (18, &[&a, &b, &c, &d, &d, &d]),
+        (16, &[&a, &c, &c, &c, &c, &e]),
+        (-4, &[&a, &c, &c, &c, &d, &d]),
+        (-27, &[&b, &b, &b, &b, &e, &e]),
+        (18, &[&b, &b, &b, &c, &d, &e]),
+        (-4, &[&b, &b, &b, &d, &d, &d]),
+        (-4, &[&b, &b, &c, &c, &c, &e]),
+        (1, &[&b, &b, &c, &c, &d, &d]),
+    ])?);
+    solve_closed(sol)
+}
+
+fn coefs<const N: usize>(sol: &Solution) -> [Im; N] {
+    std::array::from_fn(|pow| get_coef(&sol.reduced, pow as i32))
+}
+
+fn weighted_sum(terms: &[(i64, &[&Im])]) -> Result<Im, CErr> {
+    let mut sum = Im::new(0.0, 0.0);
+    for (weight, factors) in terms.iter() {
+        let mut term = Im::new(*weight as f64, 0.0);
+        for factor in factors.iter() {
+            term = term.mul(factor)?;
+        }
+        sum = sum.add(&term)?;
+    }
+    Ok(sum)
+}
+
+fn solve_closed(sol: &mut Solution) -> ComputorResult {
+    let mut poly: Vec<Im> = (0..=sol.degree)
+        .map(|pow| get_coef(&sol.reduced, pow))
+        .collect();
+    let mut roots: Vec<Root> = Vec::new();
+    while poly.len() > 3 {
+        let root = match exact_root(&poly)? {
+            Some(root) => root,
+            None => break,
+        };
+        let real_poly = poly.iter().all(Im::is_real);
+        poly = deflate(&poly, &root)?;
+        if !root.is_real() && real_poly {
+            let conj = Im::from_parts(root.get_real(), -root.get_irreal());
+            poly = deflate(&poly, &conj)?;
+            roots.push((conj, None));
+        }
+        roots.push((root, None));
+    }
+    match poly.len() {
+        2 => roots.push((-poly[0].div(&poly[1])?, None)),
+        3 => roots.extend(quadratic(&poly[0], &poly[1], &poly[2])?.1),
+        _ => {
+            for root in float_roots(&poly) {
+                roots.push((to_root(root)?, None));
+            }
+        }
+    }
+    (sol.roots, sol.surds) = sort_roots(roots).into_iter().unzip();
+    Ok(())
+}
+
+pub fn sort_roots(mut roots: Vec<Root>) -> Vec<Root> {
+    roots.sort_by(|(l, _), (r, _)| {
+        let key = |val: &Im| {
+            (
+                !val.is_real(),
+                val.get_real().get_val(),
+                val.get_irreal().get_val(),
+            )
+        };
+        key(l)
+            .partial_cmp(&key(r))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    roots.dedup_by(|(l, _), (r, _)| l == r);
+    roots
+}
+
+pub fn to_root(root: Complex) -> Result<Im, CErr> {
+    let root = if root.im.abs() <= REAL_TOLERANCE * root.norm().max(1.0) {
+        Complex::new(root.re, 0.0)
+    } else {
+        root
+    };
+    match root.to_im() {
+        Some(val) => Ok(val),
+        None => Err(CErr::unsolvable_eq("root out of float range")),
+    }
+}
+
+pub fn eval(poly: &[Im], x: &Im) -> Result<Im, CErr> {
+    let mut res = Im::new(0.0, 0.0);
+    for coef in poly.iter().rev() {
+        res = res.mul(x)?.add(coef)?;
+    }
+    Ok(res)
+}
+
+pub fn deflate(poly: &[Im], root: &Im) -> Result<Vec<Im>, CErr> {
+    let mut quotient = vec![Im::new(0.0, 0.0); poly.len() - 1];
+    let mut carry = Im::new(0.0, 0.0);
+    for pow in (1..poly.len()).rev() {
+        carry = carry.mul(root)?.add(&poly[pow])?;
+        quotient[pow - 1] = carry.clone();
+    }
+    Ok(quotient)
+}
+
+pub fn exact_root(poly: &[Im]) -> Result<Option<Im>, CErr> {
+    let floats = float_roots(poly);
+    for root in floats.iter() {
+        let real_part = convergents(root.re);
+        let irreal_part = match root.im.abs() <= REAL_TOLERANCE {
+            true => vec![Rational::zero()],
+            false => convergents(root.im),
+        };
+        for re in real_part.iter() {
+            for im in irreal_part.iter() {
+                let candidate = Im::from_parts(re.clone(), im.clone());
+                if eval(poly, &candidate)? == Im::new(0.0, 0.0) {
+                    return Ok(Some(candidate));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn convergents(val: f64) -> Vec<Rational> {
+    let mut res: Vec<Rational> = Vec::new();
+    if !val.is_finite() || val.abs() >= 2f64.powi(64) {
+        return res;
+    }
+    let (mut num, mut prev_num): (u128, u128) = (1, 0);
+    let (mut den, mut prev_den): (u128, u128) = (0, 1);
+    let mut rest = val.abs();
+    for _ in 0..64 {
+        let int = rest.floor();
+        let step = int as u128;
+        let next_num = step.saturating_mul(num).saturating_add(prev_num);
+        let next_den = step.saturating_mul(den).saturating_add(prev_den);
+        if next_den > MAX_DENOMINATOR || next_num == u128::MAX {
+            break;
+        }
+        (prev_num, num) = (num, next_num);
+        (prev_den, den) = (den, next_den);
+        res.push(Rational::from_natural(
+            val >= 0.0,
+            natural(num),
+            natural(den),
+        ));
+        let fract = rest - int;
+        if fract < f64::EPSILON {
+            break;
+        }
+        rest = 1.0 / fract;
+    }
+    res
+}
+
+fn natural(val: u128) -> Natural {
+    Natural::from_u64((val >> 64) as u64)
+        .shl(64)
+        .add(&Natural::from_u64(val as u64))
+}
+
+pub fn float_roots(poly: &[Im]) -> Vec<Complex> {
+    let coefs: Vec<Complex> = poly.iter().map(Complex::from_im).collect();
+    let roots = match coefs.len() {
+        4 => cardano(coefs[3], coefs[2], coefs[1], coefs[0]).to_vec(),
+        5 => ferrari(&coefs).to_vec(),
+        _ => Vec::new(),
+    };
+    roots.into_iter().map(|root| polish(&coefs, root)).collect()
+}
+
+fn cardano(a: Complex, b: Complex, c: Complex, d: Complex) -> [Complex; 3] {
+    let three_a = a.scale(3.0);
+    let delta_zero = b.mul(b).sub(three_a.mul(c));
+    let delta_one = b
+        .mul(b)
+        .mul(b)
+        .scale(2.0)
+        .sub(a.mul(b).mul(c).scale(9.0))
+        .add(a.mul(a).mul(d).scale(27.0));
+    let cube = delta_zero.mul(delta_zero).mul(delta_zero).scale(4.0);
+    let root = delta_one.mul(delta_one).sub(cube).sqrt();
+    let plus = delta_one.add(root).scale(0.5);
+    let minus = delta_one.sub(root).scale(0.5);
+    let big = if plus.norm() >= minus.norm() {
+        plus
+    } else {
+        minus
+    };
+    let big = big.cbrt();
+    if big.norm() == 0.0 {
+        let triple = b.div(three_a).scale(-1.0);
+        return [triple; 3];
+    }
+    let turn = Complex::new(-0.5, 3f64.sqrt() / 2.0);
+    let mut rotated = big;
+    let mut roots = [Complex::new(0.0, 0.0); 3];
+    for root in roots.iter_mut() {
+        let sum = b.add(rotated).add(delta_zero.div(rotated));
+        *root = sum.div(three_a).scale(-1.0);
+        rotated = rotated.mul(turn);
+    }
+    roots
+}
+
+fn ferrari(coefs: &[Complex]) -> [Complex; 4] {
+    let lead = coefs[4];
+    let b = coefs[3].div(lead);
+    let c = coefs[2].div(lead);
+    let d = coefs[1].div(lead);
+    let e = coefs[0].div(lead);
+    let b2 = b.mul(b);
+    let p = c.sub(b2.scale(3.0 / 8.0));
+    let q = d.sub(b.mul(c).scale(0.5)).add(b2.mul(b).scale(1.0 / 8.0));
+    let r = e
+        .sub(b.mul(d).scale(0.25))
+        .add(b2.mul(c).scale(1.0 / 16.0))
+        .sub(b2.mul(b2).scale(3.0 / 256.0));
+    let shift = b.scale(-0.25);
+    let mut roots = [Complex::new(0.0, 0.0); 4];
+    let scale = 1.0 + p.norm() + r.norm();
+    if q.norm() <= f64::EPSILON * scale {
+        let delta = p.mul(p).sub(r.scale(4.0)).sqrt();
+        let squares = [
+            p.scale(-1.0).add(delta).scale(0.5),
+            p.scale(-1.0).sub(delta).scale(0.5),
+        ];
+        for (pos, square) in squares.iter().enumerate() {
+            roots[2 * pos] = square.sqrt().add(shift);
+            roots[2 * pos + 1] = square.sqrt().scale(-1.0).add(shift);
+        }
+        return roots;
+    }
+    let resolvent = cardano(
+        Complex::new(8.0, 0.0),
+        p.scale(8.0),
+        p.mul(p).scale(2.0).sub(r.scale(8.0)),
+        q.mul(q).scale(-1.0),
+    );
+    let mut m = resolvent[0];
+    for candidate in resolvent.iter() {
+        if candidate.norm() > m.norm() {
+            m = *candidate;
+        }
+    }
+    let s = m.scale(2.0).sqrt();
+    for (pos, sign) in [1.0, -1.0].iter().enumerate() {
+        let inner = p
+            .scale(2.0)
+            .add(m.scale(2.0))
+            .add(q.scale(2.0 * sign).div(s))
+            .scale(-1.0)
+            .sqrt();
+        let base = s.scale(*sign);
+        roots[2 * pos] = base.add(inner).scale(0.5).add(shift);
+        roots[2 * pos + 1] = base.sub(inner).scale(0.5).add(shift);
+    }
+    roots
+}
+
+fn initial_guess(poly: &[Complex]) -> Vec<Complex> {
+    let degree = poly.len() - 1;
+    let lead = poly[degree].norm();
+    let mut radius: f64 = 0.0;
+    for (pow, coef) in poly[..degree].iter().enumerate() {
+        let ratio = coef.norm() / lead;
+        radius = radius.max(ratio.powf(1.0 / (degree - pow) as f64));
+    }
+    let radius = if radius > 0.0 { 2.0 * radius } else { 1.0 };
+    (0..degree)
+        .map(|pos| {
+            let angle =
+                std::f64::consts::TAU * pos as f64 / degree as f64 + 0.4;
+            Complex::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+fn rounding_bound(poly: &[Complex], x: Complex) -> f64 {
+    let mut bound: f64 = 0.0;
+    for coef in poly.iter().rev() {
+        bound = bound * x.norm() + coef.norm();
+    }
+    4.0 * poly.len() as f64 * f64::EPSILON * bound
+}
+
+fn cluster(
+    poly: &[Complex],
+    roots: &[Complex],
+    tolerance: f64,
+) -> Vec<(Complex, usize)> {
+    let degree = roots.len() as f64;
+    let radius: Vec<f64> = roots
+        .iter()
+        .map(|root| {
+            let (value, slope) = horner(poly, *root);
+            let newton = degree * value.norm() / slope.norm();
+            let floor = tolerance * root.norm().max(1.0);
+            if newton.is_finite() {
+                newton.max(floor)
+            } else {
+                floor
+            }
+        })
+        .collect();
+    let mut group: Vec<usize> = (0..roots.len()).collect();
+    for pos in 0..roots.len() {
+        for other in 0..pos {
+            let gap = roots[pos].sub(roots[other]).norm();
+            if gap <= radius[pos] + radius[other] {
+                let (from, into) = (group[pos], group[other]);
+                group.iter_mut().filter(|id| **id == from).for_each(|id| {
+                    *id = into;
+                });
+            }
+        }
+    }
+    let mut groups: Vec<(Complex, usize)> = Vec::new();
+    for id in 0..roots.len() {
+        let members: Vec<Complex> = (0..roots.len())
+            .filter(|pos| group[*pos] == id)
+            .map(|pos| roots[pos])
+            .collect();
+        if !members.is_empty() {
+            let sum = members
+                .iter()
+                .fold(Complex::new(0.0, 0.0), |sum, root| sum.add(*root));
+            groups.push((sum.scale(1.0 / members.len() as f64), members.len()));
+        }
+    }
+    groups
+}
+
+fn derivative(poly: &[Complex], order: usize) -> Vec<Complex> {
+    let mut res = poly.to_vec();
+    for _ in 0..order {
+        res = res
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(pow, coef)| coef.scale(pow as f64))
+            .collect();
+    }
+    res
+}
+
+fn polish(coefs: &[Complex], mut root: Complex) -> Complex {
+    let mut residual = horner(coefs, root).0.norm();
+    for _ in 0..POLISH_STEPS {
+        let (value, slope) = horner(coefs, root);
+        if residual == 0.0 || slope.norm() == 0.0 {
+            break;
+        }
+        let next = root.sub(value.div(slope));
+        let next_residual = horner(coefs, next).0.norm();
+        if next_residual.is_nan() || next_residual >= residual {
+            break;
+        }
+        root = next;
+        residual = next_residual;
+    }
+    root
+}
+
+pub fn horner(coefs: &[Complex], x: Complex) -> (Complex, Complex) {
+    let mut value = Complex::new(0.0, 0.0);
+    let mut slope = Complex::new(0.0, 0.0);
+    for coef in coefs.iter().rev() {
+        slope = slope.mul(x).add(value);
+        value = value.mul(x).add(*coef);
+    }
+    (value, slope)
+}
+
+#[cfg(test)]
+mod solve {
+    use super::super::eq_degree_two;
+    use super::{cardano, eq_degree_four, eq_degree_three, ferrari};
+    use super::{RootFinder, Solution};
+    use crate::arg_parse::Param;
+    use crate::types::{Complex, Imaginary as Im};
+
+    fn close(left: Complex, right: Complex) -> bool {
+        left.sub(right).norm() < 1e-9
+    }
+
+    fn expand(roots: &[f64]) -> Vec<f64> {
+        let mut coefs = vec![1.0];
+        for root in roots.iter() {
+            let mut next = vec![0.0; coefs.len() + 1];
+            for (pow, coef) in coefs.iter().enumerate() {
+                next[pow + 1] += coef;
+                next[pow] -= root * coef;
+            }
+            coefs = next;
+        }
+        coefs
+    }
+
+    fn solution(coefs: &[f64]) -> Solution {
+        let reduced = coefs
+            .iter()
+            .enumerate()
+            .filter(|(_, coef)| **coef != 0.0)
+            .map(|(pow, coef)| (pow as i32, Im::new(*coef, 0.0)))
+            .collect();
+        Solution::new(String::from("x"), coefs.len() as i32 - 1, reduced)
+    }
+
+    fn complex(coefs: &[(f64, f64)]) -> Solution {
+        let reduced = coefs
+            .iter()
+            .enumerate()
+            .map(|(pow, (re, im))| (pow as i32, Im::new(*re, *im)))
+            .collect();
+        Solution::new(String::from("x"), coefs.len() as i32 - 1, reduced)
+    }
+
+    fn has_roots(sol: &Solution, expected: &[(f64, f64)]) -> bool {
+        sol.roots.len() == expected.len()
+            && expected.iter().all(|(re, im)| {
+                let expected = Complex::new(*re, *im);
+                sol.roots
+                    .iter()
+                    .any(|root| close(Complex::from_im(root), expected))
+            })
+    }
+
+    fn reals(roots: &[Im]) -> Vec<f64> {
+        roots.iter().map(|root| root.get_real().get_val()).collect()
+    }
+
+    #[test]
+    fn cardano_roots() {
+        let c: Vec<Complex> = expand(&[1.0, -2.0, 4.0])
+            .iter()
+            .map(|coef| Complex::new(*coef, 0.0))
+            .collect();
+        let mut roots: Vec<f64> = cardano(c[3], c[2], c[1], c[0])
+            .iter()
+            .inspect(|root| assert!(root.im.abs() < 1e-9))
+            .map(|root| root.re)
+            .collect();
+        roots.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        for (root, expected) in roots.iter().zip([-2.0, 1.0, 4.0]) {
+            assert!((root - expected).abs() < 1e-9);
+        }
+
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let roots = cardano(one, zero, zero, one.scale(-1.0));
+        let half = 3f64.sqrt() / 2.0;
+        for expected in [(1.0, 0.0), (-0.5, half), (-0.5, -half)] {
+            let expected = Complex::new(expected.0, expected.1);
+            assert!(roots.iter().any(|root| close(*root, expected)));
+        }
+    }
+
+    #[test]
+    fn ferrari_roots() {
+        let coefs: Vec<Complex> = expand(&[-3.0, -1.0, 0.5, 2.0])
+            .iter()
+            .map(|coef| Complex::new(*coef, 0.0))
+            .collect();
+        let roots = ferrari(&coefs);
+        for expected in [-3.0, -1.0, 0.5, 2.0] {
+            let expected = Complex::new(expected, 0.0);
+            assert!(roots.iter().any(|root| close(*root, expected)));
+        }
+
+        let biquadratic =
+            [4.0, 0.0, 5.0, 0.0, 1.0].map(|c| Complex::new(c, 0.0));
+        let roots = ferrari(&biquadratic);
+        for expected in [1.0, -1.0, 2.0, -2.0] {
+            let expected = Complex::new(0.0, expected);
+            assert!(roots.iter().any(|root| close(*root, expected)));
+        }
+    }
+
+    #[test]
+    fn degree_three() {
+        let mut sol = solution(&expand(&[1.0, 2.0, 3.0]));
+        eq_degree_three(&mut sol).unwrap();
+
+        assert_eq!(sol.discriminant, Some(Im::new(4.0, 0.0)));
+        assert_eq!(reals(&sol.roots), vec![1.0, 2.0, 3.0]);
+
+        let mut sol = solution(&[-2.0, 0.0, 0.0, 1.0]);
+        eq_degree_three(&mut sol).unwrap();
+
+        assert_eq!(sol.roots.len(), 3);
+        assert!(sol.roots[0].is_real());
+        assert!((reals(&sol.roots)[0] - 2f64.cbrt()).abs() < 1e-9);
+        assert!(!sol.roots[1].is_real() && !sol.roots[2].is_real());
+    }
+
+    #[test]
+    fn complex_coefficients() {
+        let mut sol = complex(&[(2.0, 0.0), (0.0, 1.0), (1.0, 0.0)]);
+        eq_degree_two(&mut sol).unwrap();
+
+        assert_eq!(sol.discriminant, Some(Im::new(-9.0, 0.0)));
+        assert!(has_roots(&sol, &[(0.0, 1.0), (0.0, -2.0)]));
+
+        let mut sol =
+            complex(&[(2.0, 0.0), (-2.0, 3.0), (-1.0, -3.0), (1.0, 0.0)]);
+        eq_degree_three(&mut sol).unwrap();
+
+        assert!(has_roots(&sol, &[(1.0, 0.0), (0.0, 1.0), (0.0, 2.0)]));
+    }
+
+    #[test]
+    fn degree_four() {
+        let mut sol = solution(&expand(&[-2.0, -1.0, 1.0, 2.0]));
+        eq_degree_four(&mut sol).unwrap();
+
+        assert_eq!(reals(&sol.roots), vec![-2.0, -1.0, 1.0, 2.0]);
+
+        let mut sol = solution(&[2.0, 0.0, -3.0, 0.0, 1.0]);
+        eq_degree_four(&mut sol).unwrap();
+        let expected = [-2f64.sqrt(), -1.0, 1.0, 2f64.sqrt()];
+
+        for (root, expected) in reals(&sol.roots).iter().zip(expected) {
+            assert!((root - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn aberth_roots() {
+        let finder = RootFinder::new(&Param::default());
+        let mut sol = solution(&expand(&[-3.0, -1.0, 0.5, 2.0, 5.0]));
+        finder.solve(&mut sol).unwrap();
+        let approx = sol.approximation.unwrap();
+
+        assert_eq!(approx.multiplicities, vec![1; 5]);
+        assert!(approx.residual < 1e-6);
+        let expected = [-3.0, -1.0, 0.5, 2.0, 5.0];
+        for (root, expected) in reals(&sol.roots).iter().zip(expected) {
+            assert!((root - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn aberth_multiplicity() {
+        let finder = RootFinder::new(&Param::default());
+        let mut sol = solution(&expand(&[1.0, 1.0, -2.0, -2.0, -2.0]));
+        finder.solve(&mut sol).unwrap();
+
+        assert_eq!(reals(&sol.roots), vec![-2.0, 1.0]);
+        let approx = sol.approximation.unwrap();
+        assert_eq!(approx.multiplicities, vec![3, 2]);
+
+        let mut sol = solution(&[0.0, 0.0, 0.0, -1.0, 0.0, 1.0]);
+        finder.solve(&mut sol).unwrap();
+
+        assert_eq!(reals(&sol.roots), vec![-1.0, 0.0, 1.0]);
+        let approx = sol.approximation.unwrap();
+        assert_eq!(approx.multiplicities, vec![1, 3, 1]);
+    }
+}
@@ -10,13 +10,15 @@
 /*                                                                            */
 /* ************************************************************************** */
 
-use super::ComputorError;
-use crate::memory::Value;
-use crate::types::{Imaginary, Matrix};
+use super::{ComputorError, Outcome};
+use crate::memory::{Memory, Value};
+use crate::types::{Fraction, Imaginary, Matrix, Polynomial};
 
-use std::{collections::HashMap, fmt};
+use std::fmt;
 
+#[derive(Default)]
 pub enum Computed {
+    #[default]
     None,
     Res,
     ValMat(Matrix),
@@ -24,12 +26,55 @@ pub enum Computed {
     VarCall(String, Value),
     VarSet(String),
     FunId(String, Vec<Computed>),
-    Equ(String, HashMap<i32, Imaginary>),
+    Poly(Polynomial),
+    Frac(Fraction),
 }
 
-impl Default for Computed {
-    fn default() -> Self {
-        Computed::None
+impl Computed {
+    pub fn from_poly(poly: Polynomial) -> Computed {
+        match (poly.unknowns().is_empty(), poly.as_constant()) {
+            (true, Some(val)) => Computed::ValIm(val),
+            _ => Computed::Poly(poly),
+        }
+    }
+
+    pub fn from_frac(frac: Fraction) -> Computed {
+        match frac.as_poly() {
+            Some(poly) if frac.poles().is_empty() => Computed::from_poly(poly),
+            _ => Computed::Frac(frac),
+        }
+    }
+
+    pub fn into_frac(self, mem: &Memory) -> Result<Fraction, ComputorError> {
+        match self {
+            Computed::Frac(frac) => Ok(frac),
+            other => Ok(Fraction::from_poly(other.into_poly(mem)?)),
+        }
+    }
+
+    pub fn into_poly(self, mem: &Memory) -> Result<Polynomial, ComputorError> {
+        match self {
+            Computed::None => Err(ComputorError::empty_instr()),
+            Computed::Res => Err(ComputorError::bad_resolve()),
+            Computed::ValMat(_) => Err(ComputorError::matrix_in_eq()),
+            Computed::ValIm(val) => Ok(Polynomial::constant(val)),
+            Computed::VarCall(_, Value::Im(val)) => {
+                Ok(Polynomial::constant(val))
+            }
+            Computed::VarCall(_, Value::Mat(_)) => {
+                Err(ComputorError::matrix_in_eq())
+            }
+            Computed::VarCall(_, Value::Poly(poly)) => Ok(poly),
+            Computed::VarSet(id) => Ok(Polynomial::unknown(&id)),
+            Computed::FunId(id, args) => {
+                mem.solve_fun(id, args)?.into_poly(mem)
+            }
+            Computed::Poly(poly) => Ok(poly),
+            Computed::Frac(frac) => match frac.as_poly() {
+                Some(poly) => Ok(poly),
+                None => Err(ComputorError::rational_expr()),
+            },
+        }
     }
 }
 
@@ -43,10 +88,12 @@ impl fmt::Display for Computed {
             Computed::VarCall(id, _) => write!(f, "{}", id),
             Computed::VarSet(id) => write!(f, "{}", id),
             Computed::FunId(id, _) => write!(f, "{}(...)", id),
-            Computed::Equ(_, _) => write!(f, "..."),
+            Computed::Poly(poly) => write!(f, "{}", poly),
+            Computed::Frac(frac) => write!(f, "{}", frac),
         }
     }
 }
 
 pub type TreeResult = Result<Computed, ComputorError>;
 pub type ComputorResult = Result<(), ComputorError>;
+pub type EvalResult = Result<Outcome, ComputorError>;
@@ -0,0 +1,248 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   system.rs                                          :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{ComputorError as CErr, EvalResult, Outcome};
+
+use crate::memory::Memory;
+use crate::parser::{TokenTree, TreeBranch};
+use crate::types::{Imaginary as Im, Polynomial};
+
+type TTree = Box<dyn TokenTree>;
+
+/// Solution of a linear system: every unknown, in alphabetical order, is
+/// given as a constant plus a combination of the free unknowns.
+pub struct System {
+    pub unknowns: Vec<String>,
+    pub solution: Option<Vec<Affine>>,
+}
+
+pub struct Affine {
+    pub constant: Im,
+    pub terms: Vec<(String, Im)>,
+}
+
+impl System {
+    pub fn free(&self) -> Vec<&String> {
+        match &self.solution {
+            Some(values) => self
+                .unknowns
+                .iter()
+                .zip(values.iter())
+                .filter(|(id, val)| {
+                    val.terms.len() == 1 && val.terms[0].0 == **id
+                })
+                .map(|(id, _)| id)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+struct Linear {
+    terms: Vec<(String, Im)>,
+    constant: Im,
+}
+
+impl Linear {
+    fn from_poly(poly: &Polynomial) -> Result<Linear, CErr> {
+        match poly.linear() {
+            Some((terms, constant)) => Ok(Linear { terms, constant }),
+            None => Err(CErr::unsolvable_eq("a non linear term in a system")),
+        }
+    }
+
+    fn coef(&self, id: &str) -> Im {
+        match self.terms.iter().find(|(name, _)| name == id) {
+            Some((_, coef)) => coef.clone(),
+            None => Im::new(0.0, 0.0),
+        }
+    }
+}
+
+pub fn solve(mem: &Memory, trees: Vec<TTree>) -> EvalResult {
+    let mut equations: Vec<Polynomial> = Vec::new();
+    for mut tree in trees.into_iter() {
+        let (left, right) = match tree.as_any_mut().downcast_mut::<TreeBranch>()
+        {
+            Some(branch) if branch.op_ref().symbol() == '=' => {
+                (branch.extract(true), branch.extract(false))
+            }
+            _ => return Err(CErr::uncomplete_eq()),
+        };
+        let (left, right) = match (left, right) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Err(CErr::uncomplete_eq()),
+        };
+        let left = left.compute(mem, None)?.into_poly(mem)?;
+        let right = right.compute(mem, None)?.into_poly(mem)?;
+        equations.push(left.sub(&right)?);
+    }
+    solve_equations(equations)
+}
+
+pub fn solve_equations(equations: Vec<Polynomial>) -> EvalResult {
+    let mut unknowns: Vec<String> = Vec::new();
+    for eq in equations.iter() {
+        for id in eq.unknowns().iter() {
+            if !unknowns.contains(id) {
+                unknowns.push(id.clone());
+            }
+        }
+    }
+    unknowns.sort();
+    let mut linear: Vec<Linear> = Vec::new();
+    for eq in equations.iter() {
+        linear.push(Linear::from_poly(eq)?);
+    }
+    let solution = eliminate(&linear, &unknowns)?;
+    Ok(Outcome::SystemSolved(System { unknowns, solution }))
+}
+
+fn eliminate(
+    equations: &[Linear],
+    unknowns: &[String],
+) -> Result<Option<Vec<Affine>>, CErr> {
+    let zero = Im::new(0.0, 0.0);
+    let width = unknowns.len();
+    let mut rows: Vec<Vec<Im>> = equations
+        .iter()
+        .map(|eq| {
+            let mut row: Vec<Im> =
+                unknowns.iter().map(|id| eq.coef(id)).collect();
+            row.push(-eq.constant.clone());
+            row
+        })
+        .collect();
+    let mut pivots: Vec<usize> = Vec::new();
+    for col in 0..width {
+        let top = pivots.len();
+        let found = (top..rows.len()).find(|row| rows[*row][col] != zero);
+        let pivot = match found {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(top, pivot);
+        let lead = rows[top][col].clone();
+        for cell in rows[top].iter_mut() {
+            *cell = cell.div(&lead)?;
+        }
+        let pivot_row = rows[top].clone();
+        for (pos, row) in rows.iter_mut().enumerate() {
+            if pos == top || row[col] == zero {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (cell, lead) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                *cell = cell.sub(&factor.mul(lead)?)?;
+            }
+        }
+        pivots.push(col);
+    }
+    if rows[pivots.len()..].iter().any(|row| row[width] != zero) {
+        return Ok(None);
+    }
+    let mut values: Vec<Affine> = Vec::new();
+    for (col, id) in unknowns.iter().enumerate() {
+        values.push(match pivots.iter().position(|pivot| *pivot == col) {
+            Some(row) => Affine {
+                constant: rows[row][width].clone(),
+                terms: (0..width)
+                    .filter(|free| !pivots.contains(free))
+                    .filter(|free| rows[row][*free] != zero)
+                    .map(|free| {
+                        (unknowns[free].clone(), -rows[row][free].clone())
+                    })
+                    .collect(),
+            },
+            None => Affine {
+                constant: zero.clone(),
+                terms: vec![(id.clone(), Im::new(1.0, 0.0))],
+            },
+        });
+    }
+    Ok(Some(values))
+}
+
+#[cfg(test)]
+mod elimination {
+    use super::{solve_equations, System};
+    use crate::computor::Outcome;
+    use crate::types::{Imaginary as Im, Polynomial};
+
+    fn linear(terms: &[(&str, f64)], constant: f64) -> Polynomial {
+        let mut poly = Polynomial::constant(Im::new(constant, 0.0));
+        for (id, coef) in terms.iter() {
+            let coef = Polynomial::constant(Im::new(*coef, 0.0));
+            let term = coef.mul(&Polynomial::unknown(id)).unwrap();
+            poly = poly.add(&term).unwrap();
+        }
+        poly
+    }
+
+    fn system(equations: Vec<Polynomial>) -> System {
+        match solve_equations(equations).unwrap() {
+            Outcome::SystemSolved(sys) => sys,
+            _ => panic!("not solved as a system"),
+        }
+    }
+
+    #[test]
+    fn single_solution() {
+        let sys = system(vec![
+            linear(&[("x", 1.0), ("y", 1.0)], -3.0),
+            linear(&[("x", 1.0), ("y", -1.0)], -1.0),
+        ]);
+        let values = sys.solution.unwrap();
+
+        assert_eq!(sys.unknowns, vec!["x", "y"]);
+        assert_eq!(values[0].constant, Im::new(2.0, 0.0));
+        assert_eq!(values[1].constant, Im::new(1.0, 0.0));
+        assert!(values.iter().all(|val| val.terms.is_empty()));
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let sys = system(vec![
+            linear(&[("x", 1.0), ("y", 1.0), ("z", 1.0)], -6.0),
+            linear(&[("x", 2.0), ("y", 2.0), ("z", 2.0)], -12.0),
+            linear(&[("x", 1.0), ("y", -1.0)], 0.0),
+        ]);
+
+        assert_eq!(sys.free(), vec!["z"]);
+        let values = sys.solution.unwrap();
+        for val in values[..2].iter() {
+            assert_eq!(val.constant, Im::new(3.0, 0.0));
+            assert_eq!(
+                val.terms,
+                vec![(String::from("z"), Im::new(-0.5, 0.0))]
+            );
+        }
+    }
+
+    #[test]
+    fn inconsistent() {
+        let sys = system(vec![
+            linear(&[("x", 1.0), ("y", 1.0)], -1.0),
+            linear(&[("x", 2.0), ("y", 2.0)], -4.0),
+        ]);
+
+        assert!(sys.solution.is_none());
+        assert!(sys.free().is_empty());
+    }
+
+    #[test]
+    fn not_linear() {
+        let xy = Polynomial::unknown("x").mul(&Polynomial::unknown("y"));
+
+        assert!(solve_equations(vec![xy.unwrap()]).is_err());
+    }
+}
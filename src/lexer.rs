@@ -16,35 +16,33 @@ pub use token::Token;
 use token::new_operator;
 use token::Expression;
 use token::FunctionToken;
+use token::IndexToken;
 use token::LexerError;
 use token::MatrixToken;
 use token::Resolve;
 use token::Value;
 use token::Variable;
 
-extern crate rustyline;
-use rustyline::error::ReadlineError;
-use rustyline::Editor;
-
 use crate::arg_parse::Param;
 use crate::computor::ComputorError;
-use crate::Timer;
+use crate::timer::Timer;
 
 use std::str::Chars;
 
 extern crate colored;
 use colored::Colorize;
 
-const PROMPT: &str = "> ";
 const KEY_VERB: &str = "verbose";
 const KEY_BENCH: &str = "benchmark";
+const KEY_EXPLAIN: &str = "explain";
 
 pub struct Lexer {
     verbose: bool,
     bench: bool,
-    line: Editor<()>,
+    explain: bool,
     last_ch: Option<char>,
     depth: i32,
+    log: Vec<String>,
 }
 
 impl Lexer {
@@ -52,12 +50,17 @@ impl Lexer {
         Lexer {
             verbose: param.verbose(),
             bench: param.bench(),
-            line: Editor::new(),
+            explain: param.explain(),
             last_ch: None,
             depth: 0,
+            log: Vec::new(),
         }
     }
 
+    pub fn drain_log(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.log)
+    }
+
     pub fn verbose(&self) -> bool {
         self.verbose
     }
@@ -66,6 +69,10 @@ impl Lexer {
         self.bench
     }
 
+    pub fn explain(&self) -> bool {
+        self.explain
+    }
+
     pub fn depth(&self) -> i32 {
         self.depth
     }
@@ -74,47 +81,43 @@ impl Lexer {
         self.depth = depth;
     }
 
-    pub fn read_input(&mut self) -> Result<Vec<Box<dyn Token>>, ComputorError> {
-        let readline = self.line.readline(PROMPT);
-        match readline {
-            Ok(line) => {
-                self.line.add_history_entry(line.as_str());
-                if self.verbose {
-                    println!(
-                        "{}",
-                        format!(
-                            "{} - input read: {}",
-                            "[v:Lexer]".cyan().bold(),
-                            line
-                        )
-                        .dimmed()
-                    );
-                }
-                if !self.bench {
-                    self.lexe(line)
-                } else {
-                    let _timer = Timer::new("Lexer");
-                    self.lexe(line)
-                }
-            }
-            Err(ReadlineError::Interrupted) => Err(ComputorError::io_stop()),
-            Err(ReadlineError::Eof) => Err(ComputorError::io_stop()),
-            Err(err) => Err(ComputorError::io(&format!("{:?}", err))),
+    pub fn read_input(
+        &mut self,
+        line: String,
+    ) -> Result<Vec<Box<dyn Token>>, ComputorError> {
+        if self.verbose {
+            self.log.push(
+                format!("{} - input read: {}", "[v:Lexer]".cyan().bold(), line)
+                    .dimmed()
+                    .to_string(),
+            );
+        }
+        if !self.bench {
+            self.lexe(line)
+        } else {
+            let timer = Timer::new("Lexer");
+            let res = self.lexe(line);
+            self.log.push(timer.top());
+            res
         }
     }
 
-    fn check_keyword(&mut self, word: &String) -> bool {
-        if *word == KEY_VERB {
+    fn check_keyword(&mut self, word: &str) -> bool {
+        if word.trim() == KEY_VERB {
             self.verbose = !self.verbose;
             true
-        } else if *word == KEY_BENCH {
+        } else if word.trim() == KEY_BENCH {
             self.bench = !self.bench;
             true
+        } else if word.trim() == KEY_EXPLAIN {
+            self.explain = !self.explain;
+            true
         } else {
             false
         }
     }
 
+    #[allow(clippy::needless_return)]
     pub fn lexe(
         &mut self,
         l: String,
@@ -124,17 +127,17 @@ impl Lexer {
         }
         let cleared = self.clear_input(l);
         let mut iter = cleared.chars();
-        let tokens;
         self.depth = 0;
         self.last_ch = None;
-        tokens = self.tokenize(&mut iter, false);
-        if self.depth == 0 && iter.next() == None {
+        let tokens = self.tokenize(&mut iter, false);
+        if self.depth == 0 && iter.next().is_none() {
             return Ok(tokens);
         } else {
             return Err(ComputorError::invalid_input());
         }
     }
 
+    #[allow(clippy::needless_return, clippy::redundant_guards)]
     fn tokenize(
         &mut self,
         chars: &mut Chars,
@@ -176,7 +179,7 @@ impl Lexer {
                 },
                 None => break,
             }
-            if self.last_ch == None {
+            if self.last_ch.is_none() {
                 cur = chars.next();
             } else {
                 cur = self.last_ch.take();
@@ -185,6 +188,7 @@ impl Lexer {
         return tokens;
     }
 
+    #[allow(clippy::redundant_guards)]
     fn read_matrix(&mut self, chars: &mut Chars) -> Box<dyn Token> {
         let mut raw = String::from("[");
         let mut depth = 1;
@@ -210,8 +214,33 @@ impl Lexer {
         }
     }
 
+    fn read_index(&mut self, id: String, chars: &mut Chars) -> Box<dyn Token> {
+        let mut raw = String::new();
+        let mut depth = 1;
+        loop {
+            match chars.next() {
+                None => return Box::new(LexerError::InvalidIdx(raw)),
+                Some('[') => {
+                    depth += 1;
+                    raw.push('[');
+                }
+                Some(']') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return match IndexToken::new(self, id, raw) {
+                            Ok(index) => Box::new(index),
+                            Err(err) => Box::new(err),
+                        };
+                    }
+                    raw.push(']');
+                }
+                Some(ch) => raw.push(ch),
+            };
+        }
+    }
+
     fn read_operand(&mut self, chars: &mut Chars) -> Box<dyn Token> {
-        if self.last_ch.unwrap().is_digit(10) {
+        if self.last_ch.unwrap().is_ascii_digit() {
             self.read_val(chars)
         } else {
             self.read_id(chars)
@@ -225,7 +254,16 @@ impl Lexer {
         loop {
             match chars.next() {
                 Some(ch) if ch == '.' => raw.push(ch),
-                Some(ch) if ch.is_digit(10) => raw.push(ch),
+                Some(ch) if ch.is_ascii_digit() => raw.push(ch),
+                Some(ch) if ch == 'e' && exponent_follows(chars) => {
+                    raw.push(ch);
+                    if let Some(sign) = chars.clone().next() {
+                        if sign == '+' || sign == '-' {
+                            raw.push(sign);
+                            chars.next();
+                        }
+                    }
+                }
                 Some(ch) if ch == 'i' => {
                     raw.push(ch);
                     self.last_ch = chars.next();
@@ -254,6 +292,12 @@ impl Lexer {
         loop {
             match chars.next() {
                 Some(ch) if ch.is_alphabetic() => raw.push(ch),
+                Some(ch)
+                    if ch == '[' && !chars.clone().next().eq(&Some('[')) =>
+                {
+                    self.last_ch = None;
+                    return self.read_index(raw, chars);
+                }
                 Some(ch) if ch == '(' => {
                     if raw.len() == 1 && raw.starts_with('i') {
                         self.last_ch = Some(ch);
@@ -263,6 +307,7 @@ impl Lexer {
                         };
                     }
                     self.depth += 1;
+                    self.last_ch = None;
                     let mut param_lst: Vec<Vec<Box<dyn Token>>> = Vec::new();
                     param_lst.push(self.tokenize(chars, true));
                     while self.last_ch == Some(',') {
@@ -297,9 +342,9 @@ impl Lexer {
         }
     }
 
-    fn clear_input(&self, raw_input: String) -> String {
+    fn clear_input(&mut self, raw_input: String) -> String {
         let mut cleared = String::new();
-        let mut iter = raw_input.trim().split_whitespace();
+        let mut iter = raw_input.split_whitespace();
 
         loop {
             match iter.next() {
@@ -307,14 +352,14 @@ impl Lexer {
                 None => {
                     cleared = cleared.replace("**", "#").to_lowercase();
                     if self.verbose {
-                        println!(
-                            "{}",
+                        self.log.push(
                             format!(
                                 "{} - instruction cleared: {}",
                                 "[v:Lexer]".cyan().bold(),
                                 cleared
                             )
                             .dimmed()
+                            .to_string(),
                         );
                     }
                     return cleared;
@@ -323,3 +368,14 @@ impl Lexer {
         }
     }
 }
+
+fn exponent_follows(chars: &Chars) -> bool {
+    let mut next = chars.clone();
+    match next.next() {
+        Some(ch) if ch == '+' || ch == '-' => {
+            next.next().is_some_and(|ch| ch.is_ascii_digit())
+        }
+        Some(ch) => ch.is_ascii_digit(),
+        None => false,
+    }
+}
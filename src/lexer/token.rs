@@ -12,6 +12,7 @@
 
 mod expression;
 mod function;
+mod index;
 mod lexer_error;
 mod matrix;
 mod operator;
@@ -22,6 +23,8 @@ mod variable;
 pub use expression::Expression;
 pub use function::FunctionToken;
 pub use function::FunctionTree;
+pub use index::IndexToken;
+pub use index::IndexTree;
 pub use lexer_error::LexerError;
 pub use matrix::MatrixComp;
 pub use matrix::MatrixToken;
@@ -38,9 +41,6 @@ use crate::memory::{Extension, Memory};
 use std::any::Any;
 use std::fmt;
 
-extern crate colored;
-use colored::Colorize;
-
 pub trait Token: fmt::Display + fmt::Debug {
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
@@ -57,6 +57,7 @@ pub trait Token: fmt::Display + fmt::Debug {
     ) -> TreeResult;
 }
 
+#[allow(clippy::borrowed_box)]
 pub fn count_error(token: &Box<dyn Token>) -> i32 {
     match token.as_any().downcast_ref::<LexerError>() {
         None => match token.as_any().downcast_ref::<FunctionTree>() {
@@ -69,10 +70,20 @@ pub fn count_error(token: &Box<dyn Token>) -> i32 {
                 sub_count
             }
         },
-        Some(err) => {
-            eprintln!("{} - {}", String::from("[err:token]").red(), err);
-            1
+        Some(_) => 1,
+    }
+}
+
+pub fn list_error(token: &dyn Token, errors: &mut Vec<String>) {
+    match token.as_any().downcast_ref::<LexerError>() {
+        None => {
+            if let Some(fun) = token.as_any().downcast_ref::<FunctionTree>() {
+                for param in fun.param() {
+                    param.iter(&mut |tok| list_error(tok.as_ref(), errors));
+                }
+            }
         }
+        Some(err) => errors.push(err.to_string()),
     }
 }
 
@@ -51,6 +51,7 @@ impl Token for Expression {
 }
 
 impl Expression {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(tokens: Vec<Box<dyn Token>>) -> Self {
         Expression { tokens: tokens }
     }
@@ -59,6 +60,7 @@ impl Expression {
         self.tokens.len()
     }
 
+    #[allow(clippy::needless_return)]
     pub fn consume_tokens(&mut self) -> Vec<Box<dyn Token>> {
         let mut extractor: Vec<Box<dyn Token>> = Vec::new();
         std::mem::swap(&mut self.tokens, &mut extractor);
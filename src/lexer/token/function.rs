@@ -14,6 +14,7 @@ use super::{LexerError, Token};
 use crate::computor::{Computed as Comp, ComputorError as CError, TreeResult};
 use crate::memory::{Extension, Memory};
 use crate::parser::TokenTree;
+use crate::symbolic;
 
 use std::any::Any;
 use std::fmt;
@@ -24,6 +25,7 @@ pub struct FunctionToken {
 }
 
 impl FunctionToken {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(
         id: String,
         vars: Vec<Vec<Box<dyn Token>>>,
@@ -48,6 +50,7 @@ impl FunctionToken {
         &self.id
     }
 
+    #[allow(clippy::needless_return)]
     pub fn consume_param(&mut self) -> Vec<Vec<Box<dyn Token>>> {
         let mut extractor: Vec<Vec<Box<dyn Token>>> = Vec::new();
         std::mem::swap(&mut self.param, &mut extractor);
@@ -79,6 +82,7 @@ pub struct FunctionTree {
 }
 
 impl FunctionTree {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(id: String, vars: Vec<Box<dyn TokenTree>>) -> Self {
         FunctionTree {
             id: id,
@@ -86,9 +90,17 @@ impl FunctionTree {
         }
     }
 
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
     pub fn param(&self) -> &Vec<Box<dyn TokenTree>> {
         &self.param
     }
+
+    pub fn param_mut(&mut self) -> &mut Vec<Box<dyn TokenTree>> {
+        &mut self.param
+    }
 }
 
 impl Token for FunctionTree {
@@ -100,11 +112,15 @@ impl Token for FunctionTree {
         self
     }
 
+    #[allow(clippy::needless_return)]
     fn get_result(
         &self,
         mem: &Memory,
         ext: Option<&mut Extension>,
     ) -> TreeResult {
+        if self.id == symbolic::DIFF {
+            return Err(CError::diff_alone());
+        }
         let mut args: Vec<Comp> = Vec::new();
         match ext {
             Some(extend) => {
@@ -127,7 +143,7 @@ impl fmt::Display for FunctionToken {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut param = String::new();
         for p in &self.param {
-            param = format!("{}, {}", param, super::display_token(&p));
+            param = format!("{}, {}", param, super::display_token(p));
         }
         write!(f, "{}({})", self.id, param.trim_start_matches(", "))
     }
@@ -137,7 +153,7 @@ impl fmt::Debug for FunctionToken {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut param = String::new();
         for p in &self.param {
-            param = format!("{},{}", param, super::debug_token(&p));
+            param = format!("{},{}", param, super::debug_token(p));
         }
         write!(f, "[fun:{}({})]", self.id, param.trim_start_matches(","))
     }
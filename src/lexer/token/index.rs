@@ -0,0 +1,274 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   index.rs                                           :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{super::Lexer, LexerError, Token, Variable};
+use crate::computor::{Computed, ComputorError, TreeResult};
+use crate::memory::{Extension, Memory, Value};
+use crate::parser::{Parser, TokenTree, TreeLeaf};
+use crate::types::Matrix;
+
+use std::any::Any;
+use std::fmt;
+
+type Tokenized = Vec<Box<dyn Token>>;
+
+pub struct IndexToken {
+    var: Variable,
+    index: Vec<Option<Tokenized>>,
+}
+
+impl IndexToken {
+    pub fn new(
+        lexer: &mut Lexer,
+        id: String,
+        raw: String,
+    ) -> Result<IndexToken, LexerError> {
+        let var = Variable::new(id)?;
+        let mut index: Vec<Option<Tokenized>> = Vec::new();
+        let depth = lexer.depth();
+        for part in split_index(&raw) {
+            if part == ":" {
+                index.push(None);
+                continue;
+            }
+            match lexer.lexe(String::from(part)) {
+                Ok(tokens) if !tokens.is_empty() => index.push(Some(tokens)),
+                _ => {
+                    lexer.set_depth(depth);
+                    return Err(LexerError::InvalidIdx(raw));
+                }
+            };
+        }
+        lexer.set_depth(depth);
+        if index.len() != 2 {
+            return Err(LexerError::InvalidIdx(raw));
+        }
+        Ok(IndexToken { var, index })
+    }
+
+    pub fn var(&self) -> &Variable {
+        &self.var
+    }
+
+    pub fn consume_index(&mut self) -> Vec<Option<Tokenized>> {
+        let mut export: Vec<Option<Tokenized>> = Vec::new();
+        std::mem::swap(&mut self.index, &mut export);
+        export
+    }
+}
+
+fn split_index(raw: &str) -> Vec<&str> {
+    let mut parts: Vec<&str> = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (pos, ch) in raw.char_indices() {
+        match ch {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&raw[start..pos]);
+                start = pos + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&raw[start..]);
+    parts
+}
+
+impl fmt::Display for IndexToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let index: Vec<String> = self
+            .index
+            .iter()
+            .map(|part| match part {
+                None => String::from(":"),
+                Some(tokens) => super::display_token(tokens),
+            })
+            .collect();
+        write!(f, "{}[{}]", self.var, index.join(","))
+    }
+}
+
+impl fmt::Debug for IndexToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[idx_tok:{}]", self)
+    }
+}
+
+impl Token for IndexToken {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn get_result(
+        &self,
+        _mem: &Memory,
+        _ext: Option<&mut Extension>,
+    ) -> TreeResult {
+        Err(ComputorError::unparsed_token(self))
+    }
+}
+
+pub struct IndexTree {
+    target: Box<dyn TokenTree>,
+    index: Vec<Option<Box<dyn TokenTree>>>,
+}
+
+impl IndexTree {
+    pub fn new(
+        parser: &Parser,
+        var: Variable,
+        index: Vec<Option<Tokenized>>,
+    ) -> Result<Self, LexerError> {
+        let mut tree = IndexTree {
+            target: Box::new(TreeLeaf::new(Box::new(var))),
+            index: Vec::new(),
+        };
+        for part in index {
+            match part {
+                None => tree.index.push(None),
+                Some(tokens) => {
+                    let raw = super::display_token(&tokens);
+                    match parser.parse_tokens(tokens) {
+                        None => return Err(LexerError::InvalidIdx(raw)),
+                        Some(part) => tree.index.push(Some(part)),
+                    }
+                }
+            }
+        }
+        Ok(tree)
+    }
+
+    pub fn id(&self) -> Option<&String> {
+        let var = self.target.token().as_any().downcast_ref::<Variable>();
+        var.map(|var| var.id())
+    }
+
+    pub fn trees_mut(&mut self) -> Vec<&mut Box<dyn TokenTree>> {
+        let mut trees = vec![&mut self.target];
+        trees.extend(self.index.iter_mut().flatten());
+        trees
+    }
+
+    pub fn indices(
+        &self,
+        mem: &Memory,
+        ext: Option<&mut Extension>,
+        mat: &Matrix,
+    ) -> Result<(Option<u32>, Option<u32>), ComputorError> {
+        let ext: Option<Extension> = ext.map(|extend| extend.clone());
+        let bounds = [(mat.height(), true), (mat.width(), false)];
+        let mut res: Vec<Option<u32>> = Vec::new();
+        for (part, bound) in self.index.iter().zip(bounds) {
+            res.push(match part {
+                None => None,
+                Some(tree) => {
+                    let comp = tree.compute(mem, ext.clone().as_mut())?;
+                    Some(index_val(mem, comp, bound)?)
+                }
+            });
+        }
+        Ok((res[0], res[1]))
+    }
+}
+
+fn index_val(
+    mem: &Memory,
+    comp: Computed,
+    bound: (u32, bool),
+) -> Result<u32, ComputorError> {
+    let val = match comp {
+        Computed::ValIm(val) => val,
+        Computed::VarCall(_, Value::Im(val)) => val,
+        Computed::FunId(id, args) => {
+            return index_val(mem, mem.solve_fun(id, args)?, bound)
+        }
+        Computed::VarSet(id) => {
+            return Err(ComputorError::unknown_id(id, true))
+        }
+        other => return Err(ComputorError::bad_index(&other.to_string())),
+    };
+    let real = val.get_real();
+    if !val.is_real()
+        || !val.is_int()
+        || !(real.is_positive() || real.is_zero())
+    {
+        return Err(ComputorError::bad_index(&val.to_string()));
+    }
+    if real.get_val() > f64::from(bound.0) {
+        let index = val.to_string();
+        return Err(ComputorError::matrix_index(&index, bound.0, bound.1));
+    }
+    Ok(real.get_val() as u32)
+}
+
+impl fmt::Display for IndexTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let index: Vec<String> = self
+            .index
+            .iter()
+            .map(|part| match part {
+                None => String::from(":"),
+                Some(tree) => tree.to_string(),
+            })
+            .collect();
+        write!(f, "{}[{}]", self.target, index.join(","))
+    }
+}
+
+impl fmt::Debug for IndexTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[idx_tree:{}]", self)
+    }
+}
+
+impl Token for IndexTree {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn get_result(
+        &self,
+        mem: &Memory,
+        ext: Option<&mut Extension>,
+    ) -> TreeResult {
+        let ext: Option<Extension> = ext.map(|extend| extend.clone());
+        let mat: Matrix = match self
+            .target
+            .compute(mem, ext.clone().as_mut())?
+        {
+            Computed::ValMat(mat) => mat,
+            Computed::VarCall(_, Value::Mat(mat)) => mat,
+            Computed::VarSet(id) => {
+                return Err(ComputorError::unknown_id(id, true))
+            }
+            _ => {
+                return Err(ComputorError::not_matrix(&self.target.to_string()))
+            }
+        };
+        let (row, col) = self.indices(mem, ext.clone().as_mut(), &mat)?;
+        let slice = mat.slice(row, col)?;
+        match (row, col) {
+            (Some(_), Some(_)) => Ok(Computed::ValIm(slice.data()[0].clone())),
+            _ => Ok(Computed::ValMat(slice)),
+        }
+    }
+}
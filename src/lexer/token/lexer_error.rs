@@ -18,9 +18,11 @@ use crate::types::MatrixError;
 use std::any::Any;
 use std::{error::Error, fmt};
 
+#[allow(clippy::enum_variant_names)]
 pub enum LexerError {
     InvalidOp(char),
     InvalidMat(MatrixError),
+    InvalidIdx(String),
     InvalidVal(String),
     InvalidVar(String),
     InvalidPar(String),
@@ -34,10 +36,17 @@ impl fmt::Debug for LexerError {
         match self {
             LexerError::InvalidOp(ch) => write!(f, "!{{{}}}", ch),
             LexerError::InvalidMat(err) => write!(f, "!{{{}}}", err),
+            LexerError::InvalidIdx(word) => write!(f, "!{{[{}]}}", word),
             LexerError::InvalidVal(word) => write!(f, "!{{{}}}", word),
             LexerError::InvalidVar(word) => write!(f, "!{{{}}}", word),
             LexerError::InvalidPar(word) => write!(f, "!{{{}}}", word),
-            LexerError::InvalidFun(fun, _) => write!(f, "!{{{}}}", fun),
+            LexerError::InvalidFun(fun, param) => {
+                let mut args = String::new();
+                for p in param {
+                    args = format!("{},{}", args, super::debug_token(p));
+                }
+                write!(f, "!{{{}({})}}", fun, args.trim_start_matches(","))
+            }
         }
     }
 }
@@ -49,6 +58,9 @@ impl fmt::Display for LexerError {
                 format!("'{}' isn't a valid operator.", ch)
             }
             LexerError::InvalidMat(err) => format!("{}", err),
+            LexerError::InvalidIdx(word) => {
+                format!("'[{}]' isn't a valid matrix index.", word)
+            }
             LexerError::InvalidVal(word) => {
                 format!("'{}' isn't a valid value.", word)
             }
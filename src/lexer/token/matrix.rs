@@ -86,6 +86,7 @@ impl MatrixToken {
         self.height
     }
 
+    #[allow(clippy::needless_return)]
     pub fn consume_tokens(&mut self) -> Vec<Tokenized> {
         let mut export: Vec<Tokenized> = Vec::new();
         std::mem::swap(&mut self.tokens, &mut export);
@@ -93,6 +94,7 @@ impl MatrixToken {
     }
 }
 
+#[allow(clippy::needless_return)]
 fn new_row(
     lexer: &mut Lexer,
     raw_row: &str,
@@ -128,7 +130,7 @@ impl fmt::Display for MatrixToken {
             }
             i += 1;
             print.push('"');
-            print += &super::display_token(&cell);
+            print += &super::display_token(cell);
             print.push('"');
             if i == self.width {
                 print.push(']');
@@ -177,6 +179,7 @@ pub struct MatrixTree {
 }
 
 impl MatrixTree {
+    #[allow(clippy::needless_return)]
     pub fn new(
         parser: &Parser,
         width: u32,
@@ -252,6 +255,7 @@ impl Token for MatrixTree {
         self
     }
 
+    #[allow(clippy::needless_return)]
     fn get_result(
         &self,
         mem: &Memory,
@@ -265,7 +269,7 @@ impl Token for MatrixTree {
                         Computed::ValIm(val) => mat.push(val),
                         Computed::VarCall(_, val) => match val {
                             Value::Im(val) => mat.push(val),
-                            Value::Mat(_) => {
+                            Value::Mat(_) | Value::Poly(_) => {
                                 return Err(ComputorError::matrix_val())
                             }
                         },
@@ -274,7 +278,7 @@ impl Token for MatrixTree {
                                 Computed::ValIm(val) => mat.push(val),
                                 Computed::VarCall(_, val) => match val {
                                     Value::Im(val) => mat.push(val),
-                                    Value::Mat(_) => {
+                                    Value::Mat(_) | Value::Poly(_) => {
                                         return Err(ComputorError::matrix_val())
                                     }
                                 },
@@ -291,7 +295,7 @@ impl Token for MatrixTree {
                         Computed::ValIm(val) => mat.push(val),
                         Computed::VarCall(_, val) => match val {
                             Value::Im(val) => mat.push(val),
-                            Value::Mat(_) => {
+                            Value::Mat(_) | Value::Poly(_) => {
                                 return Err(ComputorError::matrix_val())
                             }
                         },
@@ -11,18 +11,13 @@
 /* ************************************************************************** */
 
 use super::{LexerError, Token};
-use crate::computor::{
-    filter_eq, Computed as Comp, ComputorError as CErr, TreeResult,
-};
+use crate::computor::{Computed as Comp, ComputorError as CErr, TreeResult};
 use crate::memory::{Extension, Memory, Value};
-use crate::types::{Imaginary as Im, Matrix};
+use crate::types::{Fraction, Imaginary as Im, Matrix, Polynomial};
 
 use std::any::Any;
-use std::collections::HashMap;
 use std::fmt;
 
-type Equ = HashMap<i32, Im>;
-
 pub trait Operator: Token + fmt::Display {
     fn priority(&self) -> i32;
     fn is_prior(&self, other: &dyn Operator) -> bool {
@@ -30,17 +25,21 @@ pub trait Operator: Token + fmt::Display {
     }
     fn set_prior_as_exp(&mut self);
     fn symbol(&self) -> char;
-    fn dual_var(&self, var_a: String, var_b: String) -> TreeResult;
     fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult;
-    fn new_eq(&self, var: String, val: Im, var_left: bool) -> TreeResult;
     fn dual_varcall(&self, var_a: Value, var_b: Value) -> TreeResult {
         match (var_a, var_b) {
             (Value::Im(im_a), Value::Im(im_b)) => self.op(im_a, im_b),
             (Value::Mat(mat_a), Value::Mat(mat_b)) => {
                 self.dual_mat(mat_a, mat_b)
             }
-            (Value::Im(im), Value::Mat(mat)) => self.op_mat(mat, im),
-            (Value::Mat(mat), Value::Im(im)) => self.op_mat(mat, im),
+            (Value::Im(im), Value::Mat(mat)) => self.op_mat(mat, im, false),
+            (Value::Mat(mat), Value::Im(im)) => self.op_mat(mat, im, true),
+            (Value::Poly(poly), val) => {
+                self.op_poly(poly, value_poly(val, self.symbol())?)
+            }
+            (val, Value::Poly(poly)) => {
+                self.op_poly(value_poly(val, self.symbol())?, poly)
+            }
         }
     }
     fn op_var(&self, val: Im, var: Value, var_left: bool) -> TreeResult {
@@ -52,7 +51,15 @@ pub trait Operator: Token + fmt::Display {
                     self.op(val, im)
                 }
             }
-            Value::Mat(mat) => self.op_mat(mat, val),
+            Value::Mat(mat) => self.op_mat(mat, val, var_left),
+            Value::Poly(poly) => {
+                let val = Polynomial::constant(val);
+                if var_left {
+                    self.op_poly(poly, val)
+                } else {
+                    self.op_poly(val, poly)
+                }
+            }
         }
     }
     fn op(&self, val_a: Im, val_b: Im) -> TreeResult;
@@ -63,7 +70,7 @@ pub trait Operator: Token + fmt::Display {
         mat_left: bool,
     ) -> TreeResult {
         match val {
-            Value::Im(val) => self.op_mat(mat, val),
+            Value::Im(val) => self.op_mat(mat, val, mat_left),
             Value::Mat(val) => {
                 if mat_left {
                     self.dual_mat(mat, val)
@@ -71,25 +78,25 @@ pub trait Operator: Token + fmt::Display {
                     self.dual_mat(val, mat)
                 }
             }
+            Value::Poly(_) => Err(CErr::bad_use_op(self.symbol())),
         }
     }
-    fn op_mat(&self, mat: Matrix, val: Im) -> TreeResult;
-    fn fus_eq(
-        &self,
-        id_a: String,
-        id_b: String,
-        eq_a: Equ,
-        eq_b: Equ,
-    ) -> TreeResult;
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        eq: Equ,
-        eq_left: bool,
-    ) -> TreeResult;
-    fn val_eq(&self, val: Im, id: String, eq: Equ, eq_left: bool)
-        -> TreeResult;
+    fn op_mat(&self, mat: Matrix, val: Im, mat_left: bool) -> TreeResult;
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult;
+    fn op_frac(&self, frac_a: Fraction, frac_b: Fraction) -> TreeResult;
+    fn cellwise(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
+        let res = mat_a.zip_with(&mat_b, |val_a, val_b| {
+            cell_val(self.op(val_a.clone(), val_b.clone()))
+        })?;
+        Ok(Comp::ValMat(res))
+    }
+    fn broadcast(&self, mat: Matrix, val: Im, mat_left: bool) -> TreeResult {
+        let res = mat.map(|cell| match mat_left {
+            true => cell_val(self.op(cell.clone(), val.clone())),
+            false => cell_val(self.op(val.clone(), cell.clone())),
+        })?;
+        Ok(Comp::ValMat(res))
+    }
     fn exec(&self, mem: &Memory, left: Comp, right: Comp) -> TreeResult {
         match (left, right) {
             (Comp::Res, _) => Err(CErr::bad_resolve()),
@@ -105,47 +112,26 @@ pub trait Operator: Token + fmt::Display {
             (Comp::ValMat(mat_a), Comp::ValMat(mat_b)) => {
                 self.dual_mat(mat_a, mat_b)
             }
-            (Comp::ValIm(val), Comp::ValMat(mat)) => self.op_mat(mat, val),
+            (Comp::ValIm(val), Comp::ValMat(mat)) => {
+                self.op_mat(mat, val, false)
+            }
             (Comp::VarCall(_, val), Comp::ValMat(mat)) => {
                 self.op_mat_var(mat, val, false)
             }
-            (Comp::ValMat(mat), Comp::ValIm(val)) => self.op_mat(mat, val),
+            (Comp::ValMat(mat), Comp::ValIm(val)) => {
+                self.op_mat(mat, val, true)
+            }
             (Comp::ValMat(mat), Comp::VarCall(_, val)) => {
                 self.op_mat_var(mat, val, true)
             }
-            (Comp::ValMat(_), Comp::VarSet(_)) => {
-                Err(CErr::bad_use_op(self.symbol()))
-            }
-            (Comp::VarSet(_), Comp::ValMat(_)) => {
-                Err(CErr::bad_use_op(self.symbol()))
-            }
-            (Comp::ValMat(_), Comp::Equ(_, _)) => {
-                Err(CErr::bad_use_op(self.symbol()))
-            }
-            (Comp::Equ(_, _), Comp::ValMat(_)) => {
-                Err(CErr::bad_use_op(self.symbol()))
-            }
-            (Comp::VarSet(v_a), Comp::VarSet(v_b)) => self.dual_var(v_a, v_b),
-            (Comp::VarSet(var), Comp::VarCall(_, val)) => {
-                if let Value::Im(val) = val {
-                    self.new_eq(var, val, true)
-                } else {
-                    return Err(CErr::matrix_in_eq());
-                }
-            }
-            (Comp::VarCall(_, val), Comp::VarSet(var)) => {
-                if let Value::Im(val) = val {
-                    self.new_eq(var, val, false)
-                } else {
-                    return Err(CErr::matrix_in_eq());
-                }
-            }
-            (Comp::VarSet(var), Comp::ValIm(val)) => {
-                self.new_eq(var, val, true)
-            }
-            (Comp::ValIm(val), Comp::VarSet(var)) => {
-                self.new_eq(var, val, false)
-            }
+            (
+                Comp::ValMat(_),
+                Comp::VarSet(_) | Comp::Poly(_) | Comp::Frac(_),
+            ) => Err(CErr::bad_use_op(self.symbol())),
+            (
+                Comp::VarSet(_) | Comp::Poly(_) | Comp::Frac(_),
+                Comp::ValMat(_),
+            ) => Err(CErr::bad_use_op(self.symbol())),
             (Comp::ValIm(v_a), Comp::ValIm(v_b)) => self.op(v_a, v_b),
             (Comp::ValIm(v_a), Comp::VarCall(_, v_b)) => {
                 self.op_var(v_a, v_b, false)
@@ -156,32 +142,11 @@ pub trait Operator: Token + fmt::Display {
             (Comp::VarCall(_, v_a), Comp::VarCall(_, v_b)) => {
                 self.dual_varcall(v_a, v_b)
             }
-            (Comp::Equ(id_a, eq_a), Comp::Equ(id_b, eq_b)) => {
-                self.fus_eq(id_a, id_b, eq_a, eq_b)
-            }
-            (Comp::Equ(id_eq, eq), Comp::VarSet(id_v)) => {
-                self.var_eq(id_v, id_eq, eq, true)
+            (left @ Comp::Frac(_), right) | (left, right @ Comp::Frac(_)) => {
+                self.op_frac(left.into_frac(mem)?, right.into_frac(mem)?)
             }
-            (Comp::VarSet(id_v), Comp::Equ(id_eq, eq)) => {
-                self.var_eq(id_v, id_eq, eq, false)
-            }
-            (Comp::Equ(id, eq), Comp::ValIm(v)) => self.val_eq(v, id, eq, true),
-            (Comp::ValIm(v), Comp::Equ(id, eq)) => {
-                self.val_eq(v, id, eq, false)
-            }
-            (Comp::Equ(id, eq), Comp::VarCall(_, val)) => {
-                if let Value::Im(val) = val {
-                    self.val_eq(val, id, eq, true)
-                } else {
-                    return Err(CErr::matrix_in_eq());
-                }
-            }
-            (Comp::VarCall(_, val), Comp::Equ(id, eq)) => {
-                if let Value::Im(val) = val {
-                    self.val_eq(val, id, eq, false)
-                } else {
-                    return Err(CErr::matrix_in_eq());
-                }
+            (left, right) => {
+                self.op_poly(left.into_poly(mem)?, right.into_poly(mem)?)
             }
         }
     }
@@ -266,35 +231,23 @@ impl Operator for OpEqual {
         Err(CErr::too_many_equal())
     }
 
-    fn dual_var(&self, _: String, _: String) -> TreeResult {
-        Err(CErr::too_many_equal())
-    }
-
     fn dual_mat(&self, _: Matrix, _: Matrix) -> TreeResult {
         Err(CErr::too_many_equal())
     }
 
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
-        Err(CErr::too_many_equal())
-    }
-
-    fn fus_eq(&self, _: String, _: String, _: Equ, _: Equ) -> TreeResult {
+    fn op_mat(&self, _: Matrix, _: Im, _: bool) -> TreeResult {
         Err(CErr::too_many_equal())
     }
 
-    fn var_eq(&self, _: String, _: String, _: Equ, _: bool) -> TreeResult {
+    fn exec(&self, _: &Memory, _: Comp, _: Comp) -> TreeResult {
         Err(CErr::too_many_equal())
     }
 
-    fn val_eq(&self, _: Im, _: String, _: Equ, _: bool) -> TreeResult {
+    fn op_poly(&self, _: Polynomial, _: Polynomial) -> TreeResult {
         Err(CErr::too_many_equal())
     }
 
-    fn new_eq(&self, _: String, _: Im, _: bool) -> TreeResult {
-        Err(CErr::too_many_equal())
-    }
-
-    fn exec(&self, _: &Memory, _: Comp, _: Comp) -> TreeResult {
+    fn op_frac(&self, _: Fraction, _: Fraction) -> TreeResult {
         Err(CErr::too_many_equal())
     }
 }
@@ -367,82 +320,23 @@ impl Operator for OpMul {
         }
     }
 
-    fn dual_var(&self, var_a: String, var_b: String) -> TreeResult {
-        if var_a != var_b {
-            return Err(CErr::too_many_unknown());
-        }
-        let mut eq: Equ = HashMap::new();
-        eq.insert(2, Im::new(1.0, 0.0));
-        return Ok(Comp::Equ(var_a, eq));
-    }
-
-    fn dual_mat(&self, _: Matrix, _: Matrix) -> TreeResult {
-        Err(CErr::op_matrix('*'))
+    fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
+        self.cellwise(mat_a, mat_b)
     }
 
-    fn op_mat(&self, mat: Matrix, val: Im) -> TreeResult {
+    fn op_mat(&self, mat: Matrix, val: Im, _: bool) -> TreeResult {
         match mat.apply_mul(val) {
             Ok(n_mat) => Ok(Comp::ValMat(n_mat)),
             Err(err) => Err(err),
         }
     }
 
-    fn fus_eq(
-        &self,
-        id_a: String,
-        id_b: String,
-        eq_a: Equ,
-        eq_b: Equ,
-    ) -> TreeResult {
-        if id_a != id_b {
-            return Err(CErr::too_many_unknown());
-        }
-        let mut res: Equ = HashMap::new();
-
-        for (pow_a, coef_a) in eq_a.iter() {
-            for (pow_b, coef_b) in eq_b.iter() {
-                let pow = pow_a + pow_b;
-                let coef = coef_a.mul(coef_b)?;
-                match res.get_mut(&pow) {
-                    None => {
-                        res.insert(pow, coef);
-                    }
-                    Some(prev_coef) => *prev_coef = prev_coef.add(&coef)?,
-                }
-            }
-        }
-        return Ok(Comp::Equ(id_a, res));
-    }
-
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        eq: Equ,
-        _: bool,
-    ) -> TreeResult {
-        if id_var != id_eq {
-            return Err(CErr::too_many_unknown());
-        }
-        let mut res: Equ = HashMap::new();
-
-        for (pow, coef) in eq.into_iter() {
-            res.insert(pow + 1, coef);
-        }
-        return Ok(Comp::Equ(id_var, res));
-    }
-
-    fn val_eq(&self, val: Im, id: String, mut eq: Equ, _: bool) -> TreeResult {
-        for (_, coef) in eq.iter_mut() {
-            *coef = coef.mul(&val)?;
-        }
-        return Ok(Comp::Equ(id, eq));
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult {
+        Ok(Comp::Poly(poly_a.mul(&poly_b)?))
     }
 
-    fn new_eq(&self, var: String, val: Im, _: bool) -> TreeResult {
-        let mut eq: Equ = HashMap::new();
-        eq.insert(1, val);
-        return Ok(Comp::Equ(var, eq));
+    fn op_frac(&self, frac_a: Fraction, frac_b: Fraction) -> TreeResult {
+        Ok(Comp::from_frac(frac_a.mul(&frac_b)?))
     }
 }
 
@@ -511,10 +405,6 @@ impl Operator for OpMat {
         Err(CErr::bad_use_op_mat())
     }
 
-    fn dual_var(&self, _: String, _: String) -> TreeResult {
-        Err(CErr::bad_use_op_mat())
-    }
-
     fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
         match mat_a.mul(&mat_b) {
             Ok(mat) => Ok(Comp::ValMat(mat)),
@@ -522,23 +412,15 @@ impl Operator for OpMat {
         }
     }
 
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
-        Err(CErr::bad_use_op_mat())
-    }
-
-    fn fus_eq(&self, _: String, _: String, _: Equ, _: Equ) -> TreeResult {
+    fn op_mat(&self, _: Matrix, _: Im, _: bool) -> TreeResult {
         Err(CErr::bad_use_op_mat())
     }
 
-    fn var_eq(&self, _: String, _: String, _: Equ, _: bool) -> TreeResult {
+    fn op_poly(&self, _: Polynomial, _: Polynomial) -> TreeResult {
         Err(CErr::bad_use_op_mat())
     }
 
-    fn val_eq(&self, _: Im, _: String, _: Equ, _: bool) -> TreeResult {
-        Err(CErr::bad_use_op_mat())
-    }
-
-    fn new_eq(&self, _: String, _: Im, _: bool) -> TreeResult {
+    fn op_frac(&self, _: Fraction, _: Fraction) -> TreeResult {
         Err(CErr::bad_use_op_mat())
     }
 }
@@ -611,15 +493,6 @@ impl Operator for OpAdd {
         }
     }
 
-    fn dual_var(&self, var_a: String, var_b: String) -> TreeResult {
-        if var_a != var_b {
-            return Err(CErr::too_many_unknown());
-        }
-        let mut eq: Equ = HashMap::new();
-        eq.insert(1, Im::new(2.0, 0.0));
-        return Ok(Comp::Equ(var_a, eq));
-    }
-
     fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
         match mat_a.add(&mat_b) {
             Ok(mat) => Ok(Comp::ValMat(mat)),
@@ -627,70 +500,16 @@ impl Operator for OpAdd {
         }
     }
 
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
-        Err(CErr::op_matrix('+'))
+    fn op_mat(&self, mat: Matrix, val: Im, mat_left: bool) -> TreeResult {
+        self.broadcast(mat, val, mat_left)
     }
 
-    fn fus_eq(
-        &self,
-        id_a: String,
-        id_b: String,
-        mut eq_a: Equ,
-        eq_b: Equ,
-    ) -> TreeResult {
-        if id_a != id_b {
-            return Err(CErr::too_many_unknown());
-        }
-
-        for (pow_b, coef_b) in eq_b.into_iter() {
-            match eq_a.get_mut(&pow_b) {
-                None => {
-                    eq_a.insert(pow_b, coef_b);
-                }
-                Some(coef_a) => *coef_a = coef_a.add(&coef_b)?,
-            }
-        }
-        return Ok(Comp::Equ(id_a, eq_a));
-    }
-
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        mut eq: Equ,
-        _: bool,
-    ) -> TreeResult {
-        if id_var != id_eq {
-            return Err(CErr::too_many_unknown());
-        }
-        let one: i32 = 1;
-        let one_im = Im::new(1.0, 0.0);
-
-        match eq.get_mut(&one) {
-            None => {
-                eq.insert(one, one_im);
-            }
-            Some(coef) => *coef = coef.add(&one_im)?,
-        };
-        return Ok(Comp::Equ(id_var, eq));
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult {
+        Ok(Comp::Poly(poly_a.add(&poly_b)?))
     }
 
-    fn val_eq(&self, val: Im, id: String, mut eq: Equ, _: bool) -> TreeResult {
-        let zero: i32 = 0;
-        match eq.get_mut(&zero) {
-            None => {
-                eq.insert(zero, val);
-            }
-            Some(coef) => *coef = coef.add(&val)?,
-        }
-        return Ok(Comp::Equ(id, eq));
-    }
-
-    fn new_eq(&self, var: String, val: Im, _: bool) -> TreeResult {
-        let mut eq: Equ = HashMap::new();
-        eq.insert(1, Im::new(1.0, 0.0));
-        eq.insert(0, val);
-        return Ok(Comp::Equ(var, eq));
+    fn op_frac(&self, frac_a: Fraction, frac_b: Fraction) -> TreeResult {
+        Ok(Comp::from_frac(frac_a.add(&frac_b)?))
     }
 }
 
@@ -769,100 +588,16 @@ impl Operator for OpSub {
         }
     }
 
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
-        Err(CErr::op_matrix('-'))
+    fn op_mat(&self, mat: Matrix, val: Im, mat_left: bool) -> TreeResult {
+        self.broadcast(mat, val, mat_left)
     }
 
-    fn dual_var(&self, var_a: String, var_b: String) -> TreeResult {
-        if var_a != var_b {
-            return Err(CErr::too_many_unknown());
-        }
-        return Ok(Comp::ValIm(Im::new(0.0, 0.0)));
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult {
+        Ok(Comp::Poly(poly_a.sub(&poly_b)?))
     }
 
-    fn fus_eq(
-        &self,
-        id_a: String,
-        id_b: String,
-        mut eq_a: Equ,
-        eq_b: Equ,
-    ) -> TreeResult {
-        if id_a != id_b {
-            return Err(CErr::too_many_unknown());
-        }
-
-        for (pow_b, coef_b) in eq_b.into_iter() {
-            match eq_a.get_mut(&pow_b) {
-                None => {
-                    eq_a.insert(pow_b, coef_b);
-                }
-                Some(coef_a) => *coef_a = coef_a.sub(&coef_b)?,
-            }
-        }
-        return Ok(Comp::Equ(id_a, eq_a));
-    }
-
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        mut eq: Equ,
-        eq_left: bool,
-    ) -> TreeResult {
-        if id_var != id_eq {
-            return Err(CErr::too_many_unknown());
-        }
-        let one: i32 = 1;
-        let one_im = Im::new(1.0, 0.0);
-
-        match eq.get_mut(&one) {
-            None => {
-                eq.insert(one, one_im);
-            }
-            Some(coef) => {
-                *coef = if eq_left {
-                    coef.sub(&one_im)?
-                } else {
-                    one_im.sub(coef)?
-                }
-            }
-        };
-        return Ok(Comp::Equ(id_var, eq));
-    }
-
-    fn val_eq(
-        &self,
-        val: Im,
-        id: String,
-        mut eq: Equ,
-        eq_left: bool,
-    ) -> TreeResult {
-        let zero: i32 = 0;
-        match eq.get_mut(&zero) {
-            None => {
-                eq.insert(zero, -val);
-            }
-            Some(coef) => {
-                *coef = if eq_left {
-                    coef.sub(&val)?
-                } else {
-                    val.sub(coef)?
-                }
-            }
-        }
-        return Ok(Comp::Equ(id, eq));
-    }
-
-    fn new_eq(&self, var: String, val: Im, var_left: bool) -> TreeResult {
-        let mut eq: Equ = HashMap::new();
-        if var_left {
-            eq.insert(1, Im::new(1.0, 0.0));
-            eq.insert(0, -val);
-        } else {
-            eq.insert(1, Im::new(-1.0, 0.0));
-            eq.insert(0, val);
-        }
-        return Ok(Comp::Equ(var, eq));
+    fn op_frac(&self, frac_a: Fraction, frac_b: Fraction) -> TreeResult {
+        Ok(Comp::from_frac(frac_a.sub(&frac_b)?))
     }
 }
 
@@ -937,126 +672,26 @@ impl Operator for OpDiv {
         }
     }
 
-    fn dual_var(&self, var_a: String, var_b: String) -> TreeResult {
-        if var_a != var_b {
-            return Err(CErr::too_many_unknown());
+    fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
+        if mat_a.width() != mat_b.width() || mat_a.height() != mat_b.height() {
+            return Err(CErr::op_matrix('/'));
         }
-        return Ok(Comp::ValIm(Im::new(1.0, 0.0)));
-    }
-
-    fn dual_mat(&self, _: Matrix, _: Matrix) -> TreeResult {
-        Err(CErr::op_matrix('/'))
+        self.cellwise(mat_a, mat_b)
     }
 
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
-        Err(CErr::op_matrix('/'))
-    }
-
-    fn fus_eq(
-        &self,
-        id_a: String,
-        id_b: String,
-        eq_a: Equ,
-        mut eq_b: Equ,
-    ) -> TreeResult {
-        if id_a != id_b {
-            return Err(CErr::too_many_unknown());
+    fn op_mat(&self, mat: Matrix, val: Im, mat_left: bool) -> TreeResult {
+        match mat_left {
+            true => Ok(Comp::ValMat(mat.div_scalar(&val)?)),
+            false => self.broadcast(mat, val, mat_left),
         }
-        filter_eq(&mut eq_b);
-        if eq_b.len() > 1 {
-            return Err(CErr::div_by_eq());
-        }
-        let mut res: Equ = HashMap::new();
-        let zero = Im::new(0.0, 0.0);
-
-        for (pow_a, coef_a) in eq_a.iter() {
-            for (pow_b, coef_b) in eq_b.iter() {
-                let mut pow = *pow_a;
-                let mut coef = *coef_a;
-                if *coef_b != zero {
-                    pow = pow - *pow_b;
-                    coef = coef.div(coef_b)?;
-                }
-                match res.get_mut(&pow) {
-                    None => {
-                        res.insert(pow, coef);
-                    }
-                    Some(prev_coef) => *prev_coef = prev_coef.add(&coef)?,
-                }
-            }
-        }
-        return Ok(Comp::Equ(id_a, res));
     }
 
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        eq: Equ,
-        eq_left: bool,
-    ) -> TreeResult {
-        if id_var != id_eq {
-            return Err(CErr::too_many_unknown());
-        }
-        let mut res: Equ = HashMap::new();
-        if eq_left {
-            for (pow, coef) in eq.into_iter() {
-                res.insert(pow - 1, coef);
-            }
-            return Ok(Comp::Equ(id_var, res));
-        }
-        let zero = Im::new(0.0, 0.0);
-        for (pow, coef) in eq.into_iter() {
-            if coef != zero {
-                res.insert(1 - pow, -coef);
-            }
-        }
-        if res.len() == 0 {
-            return Err(CErr::div_by_zero());
-        }
-        return Ok(Comp::Equ(id_var, res));
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult {
+        Ok(Comp::from_frac(Fraction::new(poly_a, poly_b)?))
     }
 
-    fn val_eq(
-        &self,
-        val: Im,
-        id: String,
-        mut eq: Equ,
-        eq_left: bool,
-    ) -> TreeResult {
-        if eq_left {
-            if val == Im::new(0.0, 0.0) {
-                return Err(CErr::div_by_zero());
-            }
-            for (_, coef) in eq.iter_mut() {
-                *coef = coef.div(&val)?;
-            }
-            return Ok(Comp::Equ(id, eq));
-        }
-        let mut res: Equ = HashMap::new();
-        let zero = Im::new(0.0, 0.0);
-        for (pow, coef) in eq.into_iter() {
-            if coef != zero {
-                res.insert(0 - pow, val.div(&coef)?);
-            }
-        }
-        if res.len() == 0 {
-            return Err(CErr::div_by_zero());
-        }
-        return Ok(Comp::Equ(id, res));
-    }
-
-    fn new_eq(&self, var: String, val: Im, var_left: bool) -> TreeResult {
-        if var_left && val == Im::new(0.0, 0.0) {
-            return Err(CErr::div_by_zero());
-        }
-        let mut eq: Equ = HashMap::new();
-        if var_left {
-            eq.insert(1, Im::new(1.0, 0.0).div(&val)?);
-        } else {
-            eq.insert(-1, val);
-        }
-        return Ok(Comp::Equ(var, eq));
+    fn op_frac(&self, frac_a: Fraction, frac_b: Fraction) -> TreeResult {
+        Ok(Comp::from_frac(frac_a.div(&frac_b)?))
     }
 }
 
@@ -1131,53 +766,24 @@ impl Operator for OpMod {
         }
     }
 
-    fn dual_var(&self, var_a: String, var_b: String) -> TreeResult {
-        if var_a != var_b {
-            return Err(CErr::too_many_unknown());
-        }
-        return Ok(Comp::ValIm(Im::new(0.0, 0.0)));
-    }
-
-    fn dual_mat(&self, _: Matrix, _: Matrix) -> TreeResult {
-        Err(CErr::op_matrix('%'))
+    fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
+        self.cellwise(mat_a, mat_b)
     }
 
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
+    fn op_mat(&self, _: Matrix, _: Im, _: bool) -> TreeResult {
         Err(CErr::op_matrix('%'))
     }
 
-    fn fus_eq(&self, id_a: String, id_b: String, _: Equ, _: Equ) -> TreeResult {
-        if id_a != id_b {
-            return Err(CErr::too_many_unknown());
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult {
+        match poly_b.as_constant() {
+            Some(val) if val == Im::new(0.0, 0.0) => Err(CErr::div_by_zero()),
+            Some(_) => Err(CErr::mod_with_unk()),
+            None => Ok(Comp::Poly(poly_a.divrem(&poly_b)?.1)),
         }
-        return Err(CErr::mod_with_unk());
     }
 
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        _: Equ,
-        _: bool,
-    ) -> TreeResult {
-        if id_var != id_eq {
-            return Err(CErr::too_many_unknown());
-        }
-        return Err(CErr::mod_with_unk());
-    }
-
-    fn val_eq(&self, val: Im, _: String, _: Equ, eq_left: bool) -> TreeResult {
-        if eq_left && val == Im::new(0.0, 0.0) {
-            return Err(CErr::div_by_zero());
-        }
-        return Err(CErr::mod_with_unk());
-    }
-
-    fn new_eq(&self, _: String, val: Im, var_left: bool) -> TreeResult {
-        if var_left && val == Im::new(0.0, 0.0) {
-            return Err(CErr::div_by_zero());
-        }
-        return Err(CErr::mod_with_unk());
+    fn op_frac(&self, _: Fraction, _: Fraction) -> TreeResult {
+        Err(CErr::mod_with_unk())
     }
 }
 
@@ -1253,89 +859,63 @@ impl Operator for OpPow {
         }
     }
 
-    fn dual_var(&self, _: String, _: String) -> TreeResult {
-        Err(CErr::bad_pow())
-    }
-
-    fn dual_mat(&self, _: Matrix, _: Matrix) -> TreeResult {
-        Err(CErr::op_matrix('^'))
-    }
-
-    fn op_mat(&self, _: Matrix, _: Im) -> TreeResult {
-        Err(CErr::op_matrix('^'))
+    fn dual_mat(&self, mat_a: Matrix, mat_b: Matrix) -> TreeResult {
+        self.cellwise(mat_a, mat_b)
     }
 
-    fn fus_eq(&self, id_a: String, id_b: String, _: Equ, _: Equ) -> TreeResult {
-        if id_a != id_b {
-            return Err(CErr::too_many_unknown());
+    fn op_mat(&self, mat: Matrix, val: Im, mat_left: bool) -> TreeResult {
+        if !mat_left {
+            return Err(CErr::op_matrix('^'));
         }
-        Err(CErr::bad_pow())
-    }
-
-    fn var_eq(
-        &self,
-        id_var: String,
-        id_eq: String,
-        _: Equ,
-        _: bool,
-    ) -> TreeResult {
-        if id_var != id_eq {
-            return Err(CErr::too_many_unknown());
-        }
-        Err(CErr::bad_pow())
-    }
-
-    fn val_eq(
-        &self,
-        val: Im,
-        id: String,
-        eq: Equ,
-        eq_left: bool,
-    ) -> TreeResult {
-        if !eq_left || !val.is_real() || !val.is_int() {
+        let power = val.get_real().get_val();
+        if !val.is_real()
+            || !val.is_int()
+            || power < i32::MIN as f64
+            || power > i32::MAX as f64
+        {
             return Err(CErr::bad_pow());
         }
-        let mut res: Equ = HashMap::new();
-        let power = val.get_real().get_val() as i32;
+        Ok(Comp::ValMat(mat.pow(power as i32)?))
+    }
 
-        run_power(power, (0, Im::new(1.0, 0.0)), &mut res, &eq)?;
-        return Ok(Comp::Equ(id, res));
+    fn op_poly(&self, poly_a: Polynomial, poly_b: Polynomial) -> TreeResult {
+        self.op_frac(Fraction::from_poly(poly_a), Fraction::from_poly(poly_b))
     }
 
-    fn new_eq(&self, var: String, val: Im, var_left: bool) -> TreeResult {
-        if !var_left || !val.is_real() || !val.is_int() {
-            return Err(CErr::bad_pow());
+    fn op_frac(&self, frac_a: Fraction, frac_b: Fraction) -> TreeResult {
+        match frac_b.as_poly().and_then(|poly| poly.as_constant()) {
+            Some(val)
+                if val.is_real()
+                    && val.is_int()
+                    && val.get_real().get_val() >= i32::MIN as f64
+                    && val.get_real().get_val() <= i32::MAX as f64 =>
+            {
+                let power = val.get_real().get_val() as i32;
+                match frac_a.as_poly() {
+                    Some(poly) if power >= 0 && frac_a.poles().is_empty() => {
+                        Ok(Comp::Poly(poly.pow(power)?))
+                    }
+                    _ => Ok(Comp::from_frac(frac_a.pow(power)?)),
+                }
+            }
+            _ => Err(CErr::bad_pow()),
         }
-        let mut eq: Equ = HashMap::new();
-        eq.insert(val.get_real().get_val() as i32, Im::new(1.0, 0.0));
-        return Ok(Comp::Equ(var, eq));
     }
 }
 
-fn run_power(
-    i: i32,
-    field: (i32, Im),
-    res: &mut Equ,
-    eq: &Equ,
-) -> Result<(), CErr> {
-    if i == 0 {
-        match res.get_mut(&field.0) {
-            None => {
-                res.insert(field.0, field.1);
-            }
-            Some(prev_coef) => *prev_coef = prev_coef.add(&field.1)?,
-        }
-        return Ok(());
+fn cell_val(res: TreeResult) -> Result<Im, CErr> {
+    match res? {
+        Comp::ValIm(val) => Ok(val),
+        _ => Err(CErr::matrix_val()),
     }
-    for (old_pow, old_coef) in eq.iter() {
-        run_power(
-            i - 1,
-            (field.0 + *old_pow, field.1.mul(old_coef)?),
-            res,
-            eq,
-        )?;
+}
+
+fn value_poly(val: Value, op: char) -> Result<Polynomial, CErr> {
+    match val {
+        Value::Im(val) => Ok(Polynomial::constant(val)),
+        Value::Mat(_) => Err(CErr::bad_use_op(op)),
+        Value::Poly(poly) => Ok(poly),
     }
-    Ok(())
 }
 
 fn none_on_left(right: Comp, op: char) -> TreeResult {
@@ -1345,19 +925,11 @@ fn none_on_left(right: Comp, op: char) -> TreeResult {
             Comp::VarCall(_, val) => match val {
                 Value::Im(val) => Comp::ValIm(-val),
                 Value::Mat(_) => return Err(CErr::bad_use_op(op)),
+                Value::Poly(poly) => Comp::Poly(-poly),
             },
-            Comp::VarSet(id) => {
-                let mut res: Equ = HashMap::new();
-                res.insert(1, Im::new(-1.0, 0.0));
-                Comp::Equ(id, res)
-            }
-            Comp::Equ(id, eq) => {
-                let mut res = eq;
-                for (_, coef) in res.iter_mut() {
-                    *coef = -*coef;
-                }
-                Comp::Equ(id, res)
-            }
+            Comp::VarSet(id) => Comp::Poly(-Polynomial::unknown(&id)),
+            Comp::Poly(poly) => Comp::Poly(-poly),
+            Comp::Frac(frac) => Comp::Frac(-frac),
             _ => return Err(CErr::bad_use_op(op)),
         },
         '+' => right,
@@ -46,6 +46,10 @@ impl Value {
     pub fn from(value: Imaginary) -> Self {
         Value { value }
     }
+
+    pub fn value(&self) -> &Imaginary {
+        &self.value
+    }
 }
 
 impl Token for Value {
@@ -62,6 +66,6 @@ impl Token for Value {
         _mem: &Memory,
         _ext: Option<&mut Extension>,
     ) -> TreeResult {
-        Ok(Computed::ValIm(self.value))
+        Ok(Computed::ValIm(self.value.clone()))
     }
 }
@@ -22,6 +22,7 @@ pub struct Variable {
 }
 
 impl Variable {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(id: String) -> Result<Self, LexerError> {
         let mut chars = id.chars();
 
@@ -0,0 +1,33 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   lib.rs                                             :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+pub mod arg_parse;
+mod computor;
+mod lexer;
+mod memory;
+mod parser;
+pub mod render;
+mod session;
+mod symbolic;
+mod timer;
+mod types;
+
+pub use arg_parse::{Output, Param};
+pub use computor::{
+    Affine, Approximation, ComputorError, ErrorKind, EvalResult, Outcome,
+    Solution, System,
+};
+pub use memory::Value;
+pub use session::Session;
+pub use types::{
+    Fraction, Imaginary, Matrix, Natural, Polynomial, Rational, Surd,
+};
@@ -10,21 +10,21 @@
 /*                                                                            */
 /* ************************************************************************** */
 
-mod arg_parse;
-mod computor;
-mod lexer;
-mod memory;
-mod parser;
-mod timer;
-mod types;
+mod script;
 
-use crate::arg_parse::Param;
-use crate::computor::Computor;
-use crate::computor::ErrorKind;
-use crate::lexer::Lexer;
-use crate::parser::Parser;
-use crate::timer::Timer;
-use std::{env, process};
+use computorv2::{ComputorError, Param, Rational};
+use script::Runner;
+
+extern crate rustyline;
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+
+use std::io::{self, IsTerminal};
+use std::{env, process, thread};
+
+const PROMPT: &str = "> ";
+const STACK_BASE: usize = 8 * 1024 * 1024;
+const STACK_PER_CALL: usize = 32 * 1024;
 
 fn main() {
     let exit_code = main_wrapped();
@@ -40,34 +40,58 @@ fn main_wrapped() -> i32 {
     if !param.run() {
         return 0;
     }
-    let mut lex = Lexer::new(&param);
-    let mut pars = Parser::new(&param);
-    let mut computor = Computor::new(&param);
+    let stack = STACK_BASE + param.max_depth().saturating_mul(STACK_PER_CALL);
+    let worker = thread::Builder::new()
+        .stack_size(stack)
+        .spawn(|| run(param));
+    match worker.map(|handle| handle.join()) {
+        Ok(Ok(exit_code)) => exit_code,
+        Ok(Err(_)) => 1,
+        Err(err) => {
+            let err = ComputorError::io(&err.to_string());
+            eprintln!("{}", err);
+            err.kind().exit_code()
+        }
+    }
+}
+
+fn run(param: Param) -> i32 {
+    if let Some(precision) = param.precision() {
+        Rational::set_precision(precision);
+    }
+    let mut runner = Runner::new(&param);
+    runner.set_logger(|line| eprintln!("{}", line));
 
+    if !param.evals().is_empty() || !param.files().is_empty() {
+        if !runner.run_evals(param.evals()) && param.fail_fast() {
+            return runner.exit_code();
+        }
+        for path in param.files() {
+            if !runner.run_file(path) && param.fail_fast() {
+                break;
+            }
+        }
+        return runner.exit_code();
+    }
+    if !io::stdin().is_terminal() {
+        runner.run_script("<stdin>", io::stdin().lock());
+        return runner.exit_code();
+    }
+    let mut line = Editor::<()>::new();
     loop {
-        match lex.read_input() {
-            Ok(tokens) => {
-                if tokens.len() != 0 {
-                    let tree = pars.parse_tokens(tokens);
-                    if let Some(root) = tree {
-                        if let Err(err) = computor.read_tokens(root) {
-                            eprintln!("{}", err);
-                        }
-                    }
-                } else {
-                    pars.update_param(lex.verbose(), lex.benchmark());
-                    computor.update_param(lex.verbose(), lex.benchmark());
-                }
+        match line.readline(PROMPT) {
+            Ok(input) => {
+                line.add_history_entry(input.as_str());
+                runner.eval(&input, None);
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => {
+                println!("{}", ComputorError::io_stop());
+                break;
             }
             Err(err) => {
-                if *err.kind() == ErrorKind::IOStop {
-                    println!("{}", err);
-                    break;
-                } else {
-                    eprintln!("{}", err);
-                }
+                eprintln!("{}", ComputorError::io(&format!("{:?}", err)))
             }
         }
     }
-    return 0;
+    0
 }
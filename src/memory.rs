@@ -10,6 +10,7 @@
 /*                                                                            */
 /* ************************************************************************** */
 
+pub mod builtin;
 mod extension;
 mod function;
 mod variable;
@@ -19,41 +20,61 @@ pub use function::Function;
 pub use variable::Value;
 pub use variable::Variable;
 
-use crate::computor::{Computed, ComputorError, ComputorResult, TreeResult};
+use crate::computor::{
+    Computed, ComputorError, EvalResult, Outcome, TreeResult,
+};
 use crate::parser::TokenTree;
-use std::{collections::HashMap, fmt};
+use crate::symbolic;
+use crate::types::Polynomial;
+use std::cell::Cell;
+use std::collections::HashMap;
 
 pub struct Memory {
     var: HashMap<String, Variable>,
     fun: HashMap<String, Function>,
+    depth: Cell<usize>,
+    max_depth: usize,
 }
 
 impl Memory {
-    pub fn new() -> Self {
+    pub fn new(max_depth: usize) -> Self {
         Memory {
             var: HashMap::new(),
             fun: HashMap::new(),
+            depth: Cell::new(0),
+            max_depth,
         }
     }
 
-    pub fn set_var(&mut self, name: String, val: Value) {
-        println!("{}", val);
-        self.var.insert(name.clone(), Variable::new(name, val));
+    pub fn enter_call(&self) -> Result<(), ComputorError> {
+        if self.depth.get() >= self.max_depth {
+            return Err(ComputorError::fun_depth(self.max_depth));
+        }
+        self.depth.set(self.depth.get() + 1);
+        Ok(())
     }
 
-    pub fn get_var<'ext, 'mem: 'ext>(
-        &'mem self,
-        name: &String,
-    ) -> Option<&Variable> {
+    pub fn leave_call(&self) {
+        self.depth.set(self.depth.get().saturating_sub(1));
+    }
+
+    pub fn set_var(&mut self, name: String, val: Value) -> Outcome {
+        self.var
+            .insert(name.clone(), Variable::new(name.clone(), val.clone()));
+        Outcome::Assigned(name, val)
+    }
+
+    pub fn get_var(&self, name: &String) -> Option<&Variable> {
         self.var.get(name)
     }
 
+    #[allow(clippy::while_let_loop)]
     pub fn set_fun(
         &mut self,
         name: String,
         args: Vec<Computed>,
-        mut exp: Box<dyn TokenTree>,
-    ) -> ComputorResult {
+        exp: Box<dyn TokenTree>,
+    ) -> EvalResult {
         let mut vars: Vec<String> = Vec::new();
         let mut iter = args.into_iter();
 
@@ -77,23 +98,32 @@ impl Memory {
                 None => break,
             }
         }
+        if builtin::get(&name).is_some()
+            || name == builtin::MAP
+            || name == symbolic::DIFF
+        {
+            return Err(ComputorError::fun_builtin(&name));
+        }
+        let mut exp = symbolic::expand_diff(exp, self)?;
         exp.fix_exp(self, &vars)?;
-        println!("{}", exp);
-        match self.fun.get_mut(&name) {
-            Some(fun) => fun.set(vars, exp),
-            None => {
-                let mut fun = Function::new(name.clone());
-                fun.set(vars, exp);
-                self.fun.insert(name, fun);
-            }
-        };
-        Ok(())
+        let fun = self
+            .fun
+            .entry(name.clone())
+            .or_insert_with(|| Function::new(name));
+        fun.set(vars, exp);
+        Ok(fun.definition())
     }
 
     pub fn get_fun(&self, name: &String) -> Option<&Function> {
         self.fun.get(name)
     }
 
+    pub fn is_fun(&self, name: &String) -> bool {
+        builtin::get(name).is_some()
+            || name == builtin::MAP
+            || self.fun.contains_key(name)
+    }
+
     fn solve_arg(
         &self,
         id: String,
@@ -106,11 +136,17 @@ impl Memory {
             Computed::FunId(id, sub_args) => {
                 self.solve_arg(id.clone(), self.solve_fun(id, sub_args)?)
             }
+            Computed::VarSet(id) => Ok(Value::Poly(Polynomial::unknown(&id))),
+            Computed::Poly(poly) => Ok(Value::Poly(poly)),
             _ => Err(ComputorError::fun_arg_inv(&id)),
         }
     }
 
+    #[allow(clippy::while_let_loop)]
     pub fn solve_fun(&self, name: String, args: Vec<Computed>) -> TreeResult {
+        if name == builtin::MAP {
+            return self.map_fun(args);
+        }
         let mut lst: Vec<Value> = Vec::new();
         let mut iter = args.into_iter();
 
@@ -120,13 +156,50 @@ impl Memory {
                 None => break,
             }
         }
+        if name == symbolic::DIFF {
+            return Err(ComputorError::diff_alone());
+        }
+        if let Some(builtin) = builtin::get(&name) {
+            return builtin.compute(lst);
+        }
         let fun_mem = self.get_fun(&name);
-        match fun_mem {
-            Some(fun) => fun.compute(self, lst),
-            None => Err(ComputorError::fun_undef(&name)),
+        match (fun_mem, self.get_var(&name).map(|var| var.val())) {
+            (Some(fun), _) => fun.compute(self, lst),
+            (None, Some(Value::Poly(poly))) => eval_poly(&name, poly, lst),
+            (None, _) => Err(ComputorError::fun_undef(&name)),
         }
     }
 
+    fn map_fun(&self, args: Vec<Computed>) -> TreeResult {
+        let name = String::from(builtin::MAP);
+        let mut iter = args.into_iter();
+        let fun = match iter.next() {
+            Some(Computed::VarSet(id)) | Some(Computed::VarCall(id, _)) => id,
+            _ => return Err(ComputorError::fun_arg_inv(&name)),
+        };
+        let mat = match (iter.next(), iter.next()) {
+            (Some(arg), None) => match self.solve_arg(name.clone(), arg)? {
+                Value::Mat(mat) => mat,
+                _ => return Err(ComputorError::fun_arg_inv(&name)),
+            },
+            _ => return Err(ComputorError::fun_arg_inv(&name)),
+        };
+        if fun == builtin::MAP {
+            return Err(ComputorError::fun_undef(&fun));
+        }
+        let res = mat.map(|val| {
+            let arg = vec![Computed::ValIm(val.clone())];
+            match self
+                .solve_arg(fun.clone(), self.solve_fun(fun.clone(), arg)?)?
+            {
+                Value::Im(res) => Ok(res),
+                _ => Err(ComputorError::matrix_val()),
+            }
+        })?;
+        Ok(Computed::ValMat(res))
+    }
+
+    #[allow(clippy::needless_return, clippy::ptr_arg)]
     pub fn valid_args(&self, args: &Vec<Computed>) -> bool {
         for arg in args.iter() {
             match arg {
@@ -144,6 +217,7 @@ impl Memory {
         return true;
     }
 
+    #[allow(clippy::needless_return)]
     pub fn param_to_string(
         &self,
         args: Vec<Computed>,
@@ -155,40 +229,26 @@ impl Memory {
         return Ok(param);
     }
 
-    fn var_to_string(&self) -> String {
-        let mut var_str = String::from("Variables:");
-        let mut var_iter = self.var.iter();
-
-        loop {
-            match var_iter.next() {
-                Some(var) => var_str = format!("{}\n{}", var_str, var.1),
-                None => break,
-            };
-        }
-        return var_str;
-    }
-
-    fn fun_to_string(&self) -> String {
-        let mut fun_str = String::from("Functions:");
-        let mut fun_iter = self.fun.iter();
-
-        loop {
-            match fun_iter.next() {
-                Some(fun) => fun_str = format!("{}\n{}", fun_str, fun.1),
-                None => break,
-            };
-        }
-        return fun_str;
+    pub fn dump(&self) -> Outcome {
+        let var: Vec<Variable> = self.var.values().cloned().collect();
+        let fun: Vec<String> =
+            self.fun.values().map(|f| f.to_string()).collect();
+        Outcome::MemoryDump(var, fun)
     }
 }
 
-impl fmt::Display for Memory {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}\n ---\n{}\n ---",
-            self.var_to_string(),
-            self.fun_to_string()
-        )
+fn eval_poly(name: &String, poly: Polynomial, args: Vec<Value>) -> TreeResult {
+    let point = match &args[..] {
+        [Value::Im(val)] => Polynomial::constant(val.clone()),
+        [Value::Poly(val)] => val.clone(),
+        _ => return Err(ComputorError::fun_arg_inv(name)),
+    };
+    let mut unknowns = poly.unknowns().iter();
+    match (unknowns.next(), unknowns.next()) {
+        (Some(id), None) => {
+            Ok(Computed::from_poly(poly.substitute(id, &point)?))
+        }
+        (None, _) => Ok(Computed::from_poly(poly)),
+        _ => Err(ComputorError::too_many_unknown()),
     }
 }
@@ -0,0 +1,372 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   builtin.rs                                         :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::Value;
+
+use crate::computor::{Computed, ComputorError, TreeResult};
+use crate::types::{Complex, Imaginary, Matrix, Polynomial, Rational};
+
+type BuiltinFn = fn(&str, &[Value]) -> TreeResult;
+
+pub const MAP: &str = "map";
+
+pub struct Builtin {
+    name: &'static str,
+    arity: usize,
+    fun: BuiltinFn,
+}
+
+const BUILTINS: &[Builtin] = &[
+    Builtin::new("sqrt", 1, sqrt),
+    Builtin::new("cbrt", 1, cbrt),
+    Builtin::new("exp", 1, exp),
+    Builtin::new("ln", 1, ln),
+    Builtin::new("log", 1, log),
+    Builtin::new("sin", 1, sin),
+    Builtin::new("cos", 1, cos),
+    Builtin::new("tan", 1, tan),
+    Builtin::new("asin", 1, asin),
+    Builtin::new("acos", 1, acos),
+    Builtin::new("atan", 1, atan),
+    Builtin::new("sinh", 1, sinh),
+    Builtin::new("cosh", 1, cosh),
+    Builtin::new("tanh", 1, tanh),
+    Builtin::new("asinh", 1, asinh),
+    Builtin::new("acosh", 1, acosh),
+    Builtin::new("atanh", 1, atanh),
+    Builtin::new("abs", 1, abs),
+    Builtin::new("floor", 1, floor),
+    Builtin::new("ceil", 1, ceil),
+    Builtin::new("round", 1, round),
+    Builtin::new("quo", 2, quo),
+    Builtin::new("gcd", 2, gcd),
+    Builtin::new("det", 1, det),
+    Builtin::new("inv", 1, inv),
+    Builtin::new("transpose", 1, transpose),
+    Builtin::new("trace", 1, trace),
+    Builtin::new("rank", 1, rank),
+    Builtin::new("solve", 2, solve),
+    Builtin::new("rdiv", 2, rdiv),
+];
+
+pub fn get(name: &str) -> Option<&'static Builtin> {
+    BUILTINS.iter().find(|builtin| builtin.name == name)
+}
+
+impl Builtin {
+    const fn new(name: &'static str, arity: usize, fun: BuiltinFn) -> Self {
+        Builtin { name, arity, fun }
+    }
+
+    pub fn compute(&self, args: Vec<Value>) -> TreeResult {
+        if args.len() != self.arity {
+            return Err(ComputorError::fun_arg_inv(&String::from(self.name)));
+        }
+        (self.fun)(self.name, &args)
+    }
+}
+
+fn scalar<'a>(
+    name: &str,
+    args: &'a [Value],
+) -> Result<&'a Imaginary, ComputorError> {
+    match &args[0] {
+        Value::Im(val) => Ok(val),
+        _ => Err(ComputorError::fun_arg_inv(&String::from(name))),
+    }
+}
+
+fn poly(name: &str, val: &Value) -> Result<Polynomial, ComputorError> {
+    match val {
+        Value::Im(val) => Ok(Polynomial::constant(val.clone())),
+        Value::Mat(_) => Err(ComputorError::fun_arg_inv(&String::from(name))),
+        Value::Poly(val) => Ok(val.clone()),
+    }
+}
+
+fn matrix<'a>(name: &str, val: &'a Value) -> Result<&'a Matrix, ComputorError> {
+    match val {
+        Value::Mat(val) => Ok(val),
+        _ => Err(ComputorError::fun_arg_inv(&String::from(name))),
+    }
+}
+
+fn exact(val: Imaginary) -> TreeResult {
+    Ok(Computed::ValIm(val))
+}
+
+fn float(name: &str, val: Complex) -> TreeResult {
+    match val.to_im() {
+        Some(res) => Ok(Computed::ValIm(res)),
+        None => Err(ComputorError::fun_domain(name)),
+    }
+}
+
+fn with_float(
+    name: &str,
+    args: &[Value],
+    op: fn(Complex) -> Complex,
+) -> TreeResult {
+    float(name, op(Complex::from_im(scalar(name, args)?)))
+}
+
+fn real(val: Rational) -> Imaginary {
+    Imaginary::from_parts(val, Rational::zero())
+}
+
+fn is_value(val: &Imaginary, target: f64) -> bool {
+    *val == Imaginary::new(target, 0.0)
+}
+
+pub fn exact_sqrt(val: &Imaginary) -> Option<Imaginary> {
+    let re = val.get_real();
+    let im = val.get_irreal();
+    if val.is_real() {
+        return match re.is_positive() {
+            true => Some(real(re.exact_root(2)?)),
+            false => Some(Imaginary::from_parts(
+                Rational::zero(),
+                re.abs().exact_root(2)?,
+            )),
+        };
+    }
+    let two = Rational::new(2.0);
+    let modulus = re.pow(2).ok()?.add(&im.pow(2).ok()?).ok()?.exact_root(2)?;
+    let res_re = modulus.add(&re).ok()?.div(&two).ok()?.exact_root(2)?;
+    let res_im = modulus.sub(&re).ok()?.div(&two).ok()?.exact_root(2)?;
+    let res_im = if im.is_positive() { res_im } else { -res_im };
+    Some(Imaginary::from_parts(res_re, res_im))
+}
+
+fn sqrt(name: &str, args: &[Value]) -> TreeResult {
+    match exact_sqrt(scalar(name, args)?) {
+        Some(res) => exact(res),
+        None => with_float(name, args, Complex::sqrt),
+    }
+}
+
+fn cbrt(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    if val.is_real() {
+        if let Some(res) = val.get_real().exact_root(3) {
+            return exact(real(res));
+        }
+    }
+    with_float(name, args, Complex::cbrt)
+}
+
+fn exp(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(1.0, 0.0));
+    }
+    with_float(name, args, Complex::exp)
+}
+
+fn ln(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    if is_value(val, 0.0) {
+        return Err(ComputorError::fun_domain(name));
+    }
+    if is_value(val, 1.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::ln)
+}
+
+fn log(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    if is_value(val, 0.0) {
+        return Err(ComputorError::fun_domain(name));
+    }
+    if let Some(pow) = ten_power(val) {
+        return exact(Imaginary::new(pow as f64, 0.0));
+    }
+    let ln = Complex::from_im(val).ln();
+    float(name, ln.scale(1.0 / std::f64::consts::LN_10))
+}
+
+fn ten_power(val: &Imaginary) -> Option<i32> {
+    let re = val.get_real();
+    if !val.is_real() || !re.is_positive() {
+        return None;
+    }
+    let approx = re.get_val().log10().round() as i32;
+    match Rational::new(10.0).pow(approx) {
+        Ok(pow) if pow == re => Some(approx),
+        _ => None,
+    }
+}
+
+fn sin(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::sin)
+}
+
+fn cos(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(1.0, 0.0));
+    }
+    with_float(name, args, Complex::cos)
+}
+
+fn tan(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::tan)
+}
+
+fn asin(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::asin)
+}
+
+fn acos(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 1.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::acos)
+}
+
+fn atan(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::atan)
+}
+
+fn sinh(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::sinh)
+}
+
+fn cosh(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(1.0, 0.0));
+    }
+    with_float(name, args, Complex::cosh)
+}
+
+fn tanh(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::tanh)
+}
+
+fn asinh(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::asinh)
+}
+
+fn acosh(name: &str, args: &[Value]) -> TreeResult {
+    if is_value(scalar(name, args)?, 1.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::acosh)
+}
+
+fn atanh(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    if is_value(val, 1.0) || is_value(val, -1.0) {
+        return Err(ComputorError::fun_domain(name));
+    }
+    if is_value(val, 0.0) {
+        return exact(Imaginary::new(0.0, 0.0));
+    }
+    with_float(name, args, Complex::atanh)
+}
+
+fn abs(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    if val.is_real() {
+        return exact(real(val.get_real().abs()));
+    }
+    let square = val.get_real().pow(2)?.add(&val.get_irreal().pow(2)?)?;
+    match square.exact_root(2) {
+        Some(modulus) => exact(real(modulus)),
+        None => float(name, Complex::new(Complex::from_im(val).norm(), 0.0)),
+    }
+}
+
+fn floor(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    exact(Imaginary::from_parts(
+        val.get_real().floor(),
+        val.get_irreal().floor(),
+    ))
+}
+
+fn ceil(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    exact(Imaginary::from_parts(
+        val.get_real().ceil(),
+        val.get_irreal().ceil(),
+    ))
+}
+
+fn round(name: &str, args: &[Value]) -> TreeResult {
+    let val = scalar(name, args)?;
+    exact(Imaginary::from_parts(
+        val.get_real().round(),
+        val.get_irreal().round(),
+    ))
+}
+
+fn quo(name: &str, args: &[Value]) -> TreeResult {
+    let (num, den) = (poly(name, &args[0])?, poly(name, &args[1])?);
+    Ok(Computed::from_poly(num.divrem(&den)?.0))
+}
+
+fn gcd(name: &str, args: &[Value]) -> TreeResult {
+    let (poly_a, poly_b) = (poly(name, &args[0])?, poly(name, &args[1])?);
+    Ok(Computed::from_poly(poly_a.gcd(&poly_b)?))
+}
+
+fn det(name: &str, args: &[Value]) -> TreeResult {
+    exact(matrix(name, &args[0])?.det()?)
+}
+
+fn inv(name: &str, args: &[Value]) -> TreeResult {
+    Ok(Computed::ValMat(matrix(name, &args[0])?.inverse()?))
+}
+
+fn transpose(name: &str, args: &[Value]) -> TreeResult {
+    Ok(Computed::ValMat(matrix(name, &args[0])?.transpose()))
+}
+
+fn trace(name: &str, args: &[Value]) -> TreeResult {
+    exact(matrix(name, &args[0])?.trace()?)
+}
+
+fn rank(name: &str, args: &[Value]) -> TreeResult {
+    let rank = matrix(name, &args[0])?.rank()?;
+    exact(Imaginary::new(rank as f64, 0.0))
+}
+
+fn solve(name: &str, args: &[Value]) -> TreeResult {
+    let (mat, rhs) = (matrix(name, &args[0])?, matrix(name, &args[1])?);
+    Ok(Computed::ValMat(mat.solve(rhs)?))
+}
+
+fn rdiv(name: &str, args: &[Value]) -> TreeResult {
+    let (mat_a, mat_b) = (matrix(name, &args[0])?, matrix(name, &args[1])?);
+    Ok(Computed::ValMat(mat_a.div(mat_b)?))
+}
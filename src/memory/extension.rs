@@ -26,6 +26,7 @@ impl Extension {
         }
     }
 
+    #[allow(clippy::ptr_arg)]
     pub fn add(&mut self, name: &String, val: Value) {
         self.tmp_var
             .insert(name.clone(), Variable::new(name.clone(), val));
@@ -12,11 +12,13 @@
 
 use super::{Extension, Memory, Value};
 
-use crate::computor::{ComputorError, TreeResult};
+use crate::computor::{Computed, ComputorError, Outcome, TreeResult};
 use crate::parser::TokenTree;
 
 use std::{fmt, vec::Vec};
 
+type TTree = Box<dyn TokenTree>;
+
 pub struct Function {
     name: String,
     var: Vec<String>,
@@ -24,6 +26,7 @@ pub struct Function {
 }
 
 impl Function {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(name: String) -> Self {
         Function {
             name: name,
@@ -32,6 +35,7 @@ impl Function {
         }
     }
 
+    #[allow(clippy::while_let_loop)]
     pub fn set(&mut self, mut vars: Vec<String>, expr: Box<dyn TokenTree>) {
         vars.reverse();
         self.var = Vec::new();
@@ -44,21 +48,36 @@ impl Function {
         self.expr = Some(expr);
     }
 
+    pub fn vars(&self) -> &Vec<String> {
+        &self.var
+    }
+
+    pub fn expr(&self) -> Option<&TTree> {
+        self.expr.as_ref()
+    }
+
+    #[allow(clippy::needless_return)]
     pub fn compute(&self, mem: &Memory, arg: Vec<Value>) -> TreeResult {
         if arg.len() != self.var.len() {
             return Err(ComputorError::fun_arg_inv(&self.name));
         }
         let mut extended = Extension::new();
-        for i in 0..arg.len() {
-            extended.add(&self.var[i], arg[i].clone());
+        for (name, val) in self.var.iter().zip(arg) {
+            extended.add(name, val);
         }
+        mem.enter_call()?;
         let res = match &self.expr {
-            Some(tree) => tree.compute(mem, Some(&mut extended)),
+            Some(tree) => match tree.compute(mem, Some(&mut extended)) {
+                Ok(Computed::FunId(id, args)) => mem.solve_fun(id, args),
+                res => res,
+            },
             None => Err(ComputorError::fun_undef(&self.name)),
         };
+        mem.leave_call();
         return res;
     }
 
+    #[allow(clippy::needless_return, clippy::while_let_loop)]
     fn var_to_string(&self) -> String {
         let mut var_str = String::new();
         let mut var_iter = self.var.iter();
@@ -72,13 +91,21 @@ impl Function {
         return String::from(var_str.trim_start_matches(", "));
     }
 
-    pub fn print(&self, alias: Vec<String>) {
+    pub fn print(&self, alias: Vec<String>) -> Option<Outcome> {
         let var = &self.var;
         let len = alias.len();
         if len != var.len() {
-            return;
+            return None;
         }
-        println!("{}", self);
+        Some(self.definition())
+    }
+
+    pub fn definition(&self) -> Outcome {
+        let expr = match &self.expr {
+            Some(expr) => expr.to_string(),
+            None => String::new(),
+        };
+        Outcome::FunctionDefined(self.name.clone(), self.var.clone(), expr)
     }
 }
 
@@ -10,7 +10,7 @@
 /*                                                                            */
 /* ************************************************************************** */
 
-use crate::types::{Imaginary, Matrix};
+use crate::types::{Imaginary, Matrix, Polynomial};
 use std::fmt;
 
 #[derive(Clone)]
@@ -37,6 +37,7 @@ impl Variable {
 pub enum Value {
     Im(Imaginary),
     Mat(Matrix),
+    Poly(Polynomial),
 }
 
 impl fmt::Display for Variable {
@@ -49,6 +50,9 @@ impl fmt::Display for Variable {
                 self.name,
                 val.to_string().replace(" ; ", "\n  ")
             ),
+            Value::Poly(val) => {
+                write!(f, "{}:\n  {}", self.name, val)
+            }
         }
     }
 }
@@ -60,6 +64,7 @@ impl fmt::Display for Value {
             Value::Mat(val) => {
                 write!(f, "{}", val.to_string().replace(" ; ", "\n"))
             }
+            Value::Poly(val) => write!(f, "{}", val),
         }
     }
 }
@@ -19,20 +19,23 @@ pub use tree_branch::TreeBranch;
 pub use tree_leaf::TreeLeaf;
 
 use crate::lexer::token::{
-    self, Expression, FunctionToken, FunctionTree, LexerError, MatrixToken,
-    MatrixTree,
+    self, Expression, FunctionToken, FunctionTree, IndexToken, IndexTree,
+    LexerError, MatrixToken, MatrixTree,
 };
 
 use crate::arg_parse::Param;
 use crate::lexer::Token;
 use crate::timer::Timer;
 
+use std::cell::RefCell;
+
 extern crate colored;
 use colored::Colorize;
 
 pub struct Parser {
     verbose: bool,
     bench: bool,
+    log: RefCell<Vec<String>>,
 }
 
 impl Parser {
@@ -40,9 +43,14 @@ impl Parser {
         Parser {
             verbose: param.verbose(),
             bench: param.bench(),
+            log: RefCell::new(Vec::new()),
         }
     }
 
+    pub fn drain_log(&mut self) -> Vec<String> {
+        self.log.take()
+    }
+
     pub fn update_param(&mut self, verbose: bool, benchmark: bool) {
         self.verbose = verbose;
         self.bench = benchmark;
@@ -53,22 +61,24 @@ impl Parser {
         tokens: Vec<Box<dyn Token>>,
     ) -> Option<Box<dyn TokenTree>> {
         if self.verbose {
-            println!(
-                "{}",
+            self.log.borrow_mut().push(
                 format!(
                     "{} - token stack received: {}",
                     "[v:Parser]".blue().bold(),
                     token::debug_token(&tokens)
                 )
                 .dimmed()
+                .to_string(),
             );
         }
         if !self.bench {
             self.parse(tokens)
         } else {
             let display = format!("Parser({})", token::display_token(&tokens));
-            let _timer = Timer::new(&display[..]);
-            self.parse(tokens)
+            let timer = Timer::new(&display[..]);
+            let res = self.parse(tokens);
+            self.log.borrow_mut().push(timer.top());
+            res
         }
     }
 
@@ -76,17 +86,8 @@ impl Parser {
         &self,
         mut tokens: Vec<Box<dyn Token>>,
     ) -> Option<Box<dyn TokenTree>> {
-        let mut tree: Box<dyn TokenTree>;
+        let mut tree: Box<dyn TokenTree> = self.token_to_node(tokens.pop()?)?;
 
-        match tokens.pop() {
-            Some(token) => {
-                tree = match self.token_to_node(token) {
-                    None => return None,
-                    Some(token) => token,
-                }
-            }
-            None => return None,
-        }
         loop {
             match tokens.pop() {
                 None => return Some(tree),
@@ -111,7 +112,7 @@ impl Parser {
                 None => match op.as_any_mut().downcast_mut::<FunctionToken>() {
                     None => {
                         match op.as_any_mut().downcast_mut::<MatrixToken>() {
-                            None => Some(Box::new(TreeLeaf::new(token))),
+                            None => self.leaf_to_node(token),
                             Some(mat) => self.mat_to_node(mat),
                         }
                     }
@@ -132,6 +133,7 @@ impl Parser {
         exp_token
     }
 
+    #[allow(clippy::needless_return)]
     fn fun_to_node(
         &self,
         fun: &mut FunctionToken,
@@ -152,6 +154,26 @@ impl Parser {
         return Some(Box::new(TreeLeaf::new(token)));
     }
 
+    fn leaf_to_node(
+        &self,
+        mut token: Box<dyn Token>,
+    ) -> Option<Box<dyn TokenTree>> {
+        match token.as_any_mut().downcast_mut::<IndexToken>() {
+            None => Some(Box::new(TreeLeaf::new(token))),
+            Some(index) => self.index_to_node(index),
+        }
+    }
+
+    fn index_to_node(
+        &self,
+        index: &mut IndexToken,
+    ) -> Option<Box<dyn TokenTree>> {
+        match IndexTree::new(self, index.var().clone(), index.consume_index()) {
+            Ok(tree) => Some(Box::new(TreeLeaf::new(Box::new(tree)))),
+            Err(err) => Some(Box::new(TreeLeaf::new(Box::new(err)))),
+        }
+    }
+
     fn mat_to_node(&self, mat: &mut MatrixToken) -> Option<Box<dyn TokenTree>> {
         match MatrixTree::new(
             self,
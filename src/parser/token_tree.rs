@@ -21,11 +21,13 @@ use std::fmt;
 pub trait TokenTree: fmt::Display + fmt::Debug {
     fn as_any_mut(&mut self) -> &mut dyn Any;
     fn as_any(&self) -> &dyn Any;
+    #[allow(clippy::borrowed_box)]
     fn token(&self) -> &Box<dyn Token>;
-    fn iter(&self, foo: fn(&Box<dyn Token>));
-    fn count(&self, foo: fn(&Box<dyn Token>) -> i32) -> i32;
+    fn iter(&self, fun: &mut dyn FnMut(&Box<dyn Token>));
+    fn count(&self, fun: fn(&Box<dyn Token>) -> i32) -> i32;
     fn is_full(&self) -> bool;
     fn set_as_exp(&mut self);
+    #[allow(clippy::ptr_arg)]
     fn fix_exp(&mut self, mem: &Memory, var: &Vec<String>) -> ComputorResult;
     fn compute(&self, mem: &Memory, ext: Option<&mut Extension>) -> TreeResult;
 }
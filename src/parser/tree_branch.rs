@@ -31,6 +31,7 @@ pub struct TreeBranch {
 }
 
 impl TreeBranch {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(token: Box<dyn Token>) -> Self {
         TreeBranch {
             token: token,
@@ -40,15 +41,38 @@ impl TreeBranch {
         }
     }
 
+    pub fn from_parts(
+        token: Box<dyn Token>,
+        left: Option<TTree>,
+        right: Option<TTree>,
+    ) -> Self {
+        TreeBranch {
+            token,
+            branch_left: left,
+            branch_right: right,
+            was_expr: false,
+        }
+    }
+
     pub fn was_expr(&self) -> bool {
         self.was_expr
     }
 
+    pub fn left(&self) -> Option<&TTree> {
+        self.branch_left.as_ref()
+    }
+
+    pub fn right(&self) -> Option<&TTree> {
+        self.branch_right.as_ref()
+    }
+
+    #[allow(clippy::needless_return)]
     pub fn op_mut(&mut self) -> &mut dyn Operator {
         let extractor = &mut self.token;
         return extractor.as_op_mut().unwrap();
     }
 
+    #[allow(clippy::needless_return)]
     pub fn op_ref(&self) -> &dyn Operator {
         let extractor = &self.token;
         return extractor.as_op_ref().unwrap();
@@ -260,26 +284,25 @@ impl TokenTree for TreeBranch {
         &self.token
     }
 
-    fn iter(&self, foo: fn(&Box<dyn Token>)) {
-        match &self.branch_left {
-            Some(tree) => tree.iter(foo),
-            None => {}
+    fn iter(&self, fun: &mut dyn FnMut(&Box<dyn Token>)) {
+        if let Some(tree) = &self.branch_left {
+            tree.iter(fun);
         }
-        foo(self.token());
-        match &self.branch_right {
-            Some(tree) => tree.iter(foo),
-            None => {}
+        fun(self.token());
+        if let Some(tree) = &self.branch_right {
+            tree.iter(fun);
         }
     }
 
-    fn count(&self, foo: fn(&Box<dyn Token>) -> i32) -> i32 {
+    #[allow(clippy::needless_return)]
+    fn count(&self, fun: fn(&Box<dyn Token>) -> i32) -> i32 {
         let mut sum = match &self.branch_left {
-            Some(tree) => tree.count(foo),
+            Some(tree) => tree.count(fun),
             None => 0,
         };
-        sum += foo(self.token());
+        sum += fun(self.token());
         sum += match &self.branch_right {
-            Some(tree) => tree.count(foo),
+            Some(tree) => tree.count(fun),
             None => 0,
         };
         return sum;
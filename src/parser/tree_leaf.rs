@@ -13,10 +13,10 @@
 use super::TokenTree;
 use crate::computor::{ComputorError, ComputorResult, TreeResult};
 use crate::lexer::{
-    token::{FunctionTree, MatrixComp, MatrixTree, Value, Variable},
+    token::{FunctionTree, IndexTree, MatrixComp, MatrixTree, Value, Variable},
     Token,
 };
-use crate::memory::{Extension, Memory, Value as Val};
+use crate::memory::{builtin, Extension, Memory, Value as Val};
 
 use std::any::Any;
 use std::fmt;
@@ -26,6 +26,7 @@ pub struct TreeLeaf {
 }
 
 impl TreeLeaf {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(token: Box<dyn Token>) -> Self {
         TreeLeaf { token: token }
     }
@@ -44,12 +45,12 @@ impl TokenTree for TreeLeaf {
         &self.token
     }
 
-    fn iter(&self, foo: fn(&Box<dyn Token>)) {
-        foo(self.token());
+    fn iter(&self, fun: &mut dyn FnMut(&Box<dyn Token>)) {
+        fun(self.token());
     }
 
-    fn count(&self, foo: fn(&Box<dyn Token>) -> i32) -> i32 {
-        foo(&self.token)
+    fn count(&self, fun: fn(&Box<dyn Token>) -> i32) -> i32 {
+        fun(&self.token)
     }
 
     fn is_full(&self) -> bool {
@@ -75,21 +76,29 @@ impl TokenTree for TreeLeaf {
                 Some(var) => match var.val() {
                     Val::Im(val) => Box::new(Value::from(val)),
                     Val::Mat(mat) => Box::new(MatrixComp::new(mat)),
+                    Val::Poly(_) => return Ok(()),
                 },
             };
             std::mem::swap(&mut new, &mut self.token);
         } else {
             let fun = any.downcast_mut::<FunctionTree>();
-            if let None = fun {
+            if let Some(fun) = fun {
+                let skip = usize::from(fun.id() == builtin::MAP);
+                for param in fun.param_mut().iter_mut().skip(skip) {
+                    param.fix_exp(mem, vars)?;
+                }
+            } else {
                 let mat = any.downcast_mut::<MatrixTree>();
                 if let Some(mat) = mat {
                     let trees = mat.trees_mut();
                     for tree in trees.iter_mut() {
                         tree.fix_exp(mem, vars)?;
                     }
+                } else if let Some(index) = any.downcast_mut::<IndexTree>() {
+                    for tree in index.trees_mut() {
+                        tree.fix_exp(mem, vars)?;
+                    }
                 }
-            } else {
-                return Err(ComputorError::fun_call_fun());
             }
         }
         Ok(())
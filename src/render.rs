@@ -0,0 +1,266 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   render.rs                                          :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+pub mod json;
+
+use crate::computor::{Affine, Outcome, Solution, System};
+use crate::types::{Imaginary, Rational};
+
+use std::collections::HashMap;
+
+extern crate colored;
+use colored::Colorize;
+
+pub fn outcome(outcome: &Outcome, verbose: bool) -> String {
+    match outcome {
+        Outcome::Value(val) => val.to_string(),
+        Outcome::Assigned(_, val) => val.to_string(),
+        Outcome::FunctionDefined(name, var, expr) => {
+            format!("{}({}) = {}", name, var.join(", "), expr)
+        }
+        Outcome::Derivative(of, var, expr) => {
+            format!("d/d{} {} = {}", var, of, expr)
+        }
+        Outcome::Expression(poly) => poly.to_string(),
+        Outcome::Fraction(frac) => frac.to_string(),
+        Outcome::Boolean(res) => {
+            String::from(if *res { "True" } else { "False" })
+        }
+        Outcome::EquationSolved(sol) => solution(sol, verbose),
+        Outcome::SystemSolved(sys) => system(sys),
+        Outcome::MemoryDump(var, fun) => memory(var, fun),
+        Outcome::Settings(_, _, _) => String::new(),
+    }
+}
+
+fn memory(var: &[crate::memory::Variable], fun: &[String]) -> String {
+    let mut var_str = String::from("Variables:");
+    for v in var.iter() {
+        var_str = format!("{}\n{}", var_str, v);
+    }
+    let mut fun_str = String::from("Functions:");
+    for f in fun.iter() {
+        fun_str = format!("{}\n{}", fun_str, f);
+    }
+    format!("{}\n ---\n{}\n ---", var_str, fun_str)
+}
+
+fn solution(sol: &Solution, verbose: bool) -> String {
+    let mut print = String::new();
+    for (pos, step) in sol.steps.iter().enumerate() {
+        print += &format!("{}. {}\n", pos + 1, step);
+    }
+    print += &roots(sol, verbose);
+    for root in sol.excluded.iter() {
+        print += &format!(
+            "\nExcluded, as it makes a denominator zero: {} = {}",
+            sol.id, root
+        );
+    }
+    print
+}
+
+fn roots(sol: &Solution, verbose: bool) -> String {
+    let id = &sol.id;
+    if sol.reduced.is_empty() {
+        return format!("Any value for {} is a solution.", id);
+    }
+    if sol.degree == 0 {
+        return String::from("False.");
+    }
+    let mut print = print_eq(&sol.reduced, id, sol.degree);
+    if let (true, Some(delta)) = (verbose, &sol.discriminant) {
+        print += &format!(
+            "\n{}",
+            format!("{} - Delta = {}", "[v:Computor]".purple().bold(), delta)
+                .dimmed()
+        );
+    }
+    if let Some(approx) = &sol.approximation {
+        print += &format!(
+            "\nApproximate solutions ({} iterations, residual {:.2e}):",
+            approx.iterations, approx.residual
+        );
+        for (root, count) in sol.roots.iter().zip(approx.multiplicities.iter())
+        {
+            print += &format!("\n{} = {}", id, root);
+            if *count > 1 {
+                print += &format!(" (multiplicity {})", count);
+            }
+        }
+        return print;
+    }
+    let real_eq = sol.reduced.values().all(Imaginary::is_real);
+    match (sol.degree, &sol.discriminant) {
+        (2, Some(delta)) if !real_eq => {
+            print += &format!(
+                "\nDelta is {}, {}:",
+                sign(delta),
+                count_roots(&sol.roots)
+            );
+        }
+        (2, Some(delta)) if sign(delta) == "positive" => {
+            print += "\nDelta is positive, 2 real solutions:";
+        }
+        (2, Some(delta)) if sign(delta) == "negative" => {
+            print += "\nDelta is negative, 2 imaginary solutions:";
+        }
+        (2, _) => print += "\nDelta is null, 1 real solution:",
+        (3 | 4, Some(delta)) if real_eq => {
+            print += &format!(
+                "\nDiscriminant is {}, {}:",
+                sign(delta),
+                count_roots(&sol.roots)
+            );
+        }
+        _ => {
+            for root in sol.roots.iter() {
+                print += &format!("\nSolution: {} = {}", id, root);
+            }
+            return print;
+        }
+    };
+    for (pos, root) in sol.roots.iter().enumerate() {
+        match sol.surds.get(pos) {
+            Some(Some(surd)) => {
+                print += &format!("\n{} = {} ≈ {}", id, surd, root)
+            }
+            _ => print += &format!("\n{} = {}", id, root),
+        }
+    }
+    print
+}
+
+fn sign(delta: &Imaginary) -> &'static str {
+    match delta.get_real() {
+        _ if !delta.is_real() => "complex",
+        real if real > Rational::zero() => "positive",
+        real if real < Rational::zero() => "negative",
+        _ => "null",
+    }
+}
+
+fn count_roots(roots: &[Imaginary]) -> String {
+    let real = roots.iter().filter(|root| root.is_real()).count();
+    let imaginary = roots.len() - real;
+    let plural = if roots.len() > 1 { "s" } else { "" };
+    match (real, imaginary) {
+        (real, 0) => format!("{} real solution{}", real, plural),
+        (0, imaginary) => format!("{} imaginary solution{}", imaginary, plural),
+        _ => format!("{} real and {} imaginary solutions", real, imaginary),
+    }
+}
+
+fn system(sys: &System) -> String {
+    let values = match &sys.solution {
+        Some(values) => values,
+        None => return String::from("Inconsistent system, no solution."),
+    };
+    let free = sys.free();
+    let mut print = match free.len() {
+        0 => String::from("Unique solution:"),
+        _ => format!(
+            "Infinitely many solutions, {} being free:",
+            free.iter()
+                .map(|id| id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    for (id, val) in sys.unknowns.iter().zip(values.iter()) {
+        if !free.contains(&id) {
+            print += &format!("\n{} = {}", id, affine(val));
+        }
+    }
+    print
+}
+
+fn affine(val: &Affine) -> String {
+    let zero = Imaginary::new(0.0, 0.0);
+    let mut print = String::new();
+    if val.constant != zero || val.terms.is_empty() {
+        print += &val.constant.to_string();
+    }
+    for (id, coef) in val.terms.iter() {
+        let one = Imaginary::new(1.0, 0.0);
+        let (sign, coef) =
+            match coef.is_real() && !coef.get_real().is_positive() {
+                true => ("-", -coef.clone()),
+                false => ("+", coef.clone()),
+            };
+        let term = match coef == one {
+            true => id.clone(),
+            false if coef.is_real() || coef.get_real().is_zero() => {
+                format!("{} * {}", coef, id)
+            }
+            false => format!("({}) * {}", coef, id),
+        };
+        print = match (print.is_empty(), sign) {
+            (true, "-") => format!("- {}", term),
+            (true, _) => term,
+            (false, sign) => format!("{} {} {}", print, sign, term),
+        };
+    }
+    print
+}
+
+pub fn print_eq(eq: &HashMap<i32, Imaginary>, id: &str, degree: i32) -> String {
+    format!(
+        "Equation of degree {}:\n{} = 0",
+        degree,
+        print_terms(eq, id, degree)
+    )
+}
+
+fn print_terms(eq: &HashMap<i32, Imaginary>, id: &str, degree: i32) -> String {
+    let mut terms: Vec<(i32, &Imaginary)> = Vec::new();
+    let mut pow: i32 = degree;
+    while terms.len() < eq.len() {
+        if let Some(coef) = eq.get(&pow) {
+            terms.push((pow, coef));
+        }
+        pow -= 1;
+    }
+    print_seq(&terms, id)
+}
+
+pub fn print_seq(terms: &[(i32, &Imaginary)], id: &str) -> String {
+    let mut print = String::new();
+    for (pos, (pow, val)) in terms.iter().enumerate() {
+        let pow = *pow;
+        let negative = match val.get_real() {
+            real if real.is_zero() => !val.get_irreal().is_positive(),
+            real => !real.is_positive(),
+        };
+        let both = !val.is_real() && !val.get_real().is_zero();
+        print += &format!(
+            "{}{}{}{}",
+            if negative || pos == 0 { " " } else { " + " },
+            if both {
+                format!("({})", val)
+            } else {
+                val.to_string()
+            },
+            if pow != 0 {
+                format!(" * {}", id)
+            } else {
+                String::new()
+            },
+            if pow != 0 && pow != 1 {
+                format!("^{}", pow)
+            } else {
+                String::new()
+            },
+        );
+    }
+    print
+}
@@ -0,0 +1,380 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   json.rs                                            :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use crate::computor::{EvalResult, Outcome, Solution, System};
+use crate::memory::{Value, Variable};
+use crate::types::{Fraction, Imaginary, Matrix, Polynomial, Rational};
+
+pub fn result(
+    input: &str,
+    origin: Option<(&str, usize)>,
+    res: &EvalResult,
+) -> String {
+    let mut fields = vec![format!("\"input\":{}", string(input))];
+    if let Some((name, line)) = origin {
+        fields.push(format!("\"source\":{}", string(name)));
+        fields.push(format!("\"line\":{}", line));
+    }
+    match res {
+        Ok(outcome) => {
+            fields.push(format!("\"kind\":\"{}\"", kind(outcome)));
+            fields.push(format!("\"value\":{}", outcome_value(outcome)));
+            fields.push(String::from("\"error\":null"));
+        }
+        Err(err) => {
+            fields.push(String::from("\"kind\":\"error\""));
+            fields.push(String::from("\"value\":null"));
+            fields.push(format!(
+                "\"error\":{{\"kind\":\"{:?}\",\"category\":{},\"message\":{}}}",
+                err.kind(),
+                string(&err.kind().to_string()),
+                string(err.info())
+            ));
+        }
+    }
+    format!("{{{}}}", fields.join(","))
+}
+
+fn kind(outcome: &Outcome) -> &'static str {
+    match outcome {
+        Outcome::Value(_) => "value",
+        Outcome::Assigned(_, _) => "assigned",
+        Outcome::FunctionDefined(_, _, _) => "function_defined",
+        Outcome::Derivative(_, _, _) => "derivative",
+        Outcome::Expression(_) | Outcome::Fraction(_) => "expression",
+        Outcome::Boolean(_) => "boolean",
+        Outcome::EquationSolved(_) => "equation_solved",
+        Outcome::SystemSolved(_) => "system_solved",
+        Outcome::MemoryDump(_, _) => "memory_dump",
+        Outcome::Settings(_, _, _) => "settings",
+    }
+}
+
+fn outcome_value(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Value(val) => value(val),
+        Outcome::Assigned(name, val) => {
+            format!("{{\"name\":{},\"value\":{}}}", string(name), value(val))
+        }
+        Outcome::FunctionDefined(name, var, expr) => format!(
+            "{{\"name\":{},\"variables\":[{}],\"expression\":{}}}",
+            string(name),
+            var.iter().map(|v| string(v)).collect::<Vec<_>>().join(","),
+            string(expr)
+        ),
+        Outcome::Derivative(of, var, expr) => format!(
+            "{{\"of\":{},\"variable\":{},\"expression\":{}}}",
+            string(of),
+            string(var),
+            string(expr)
+        ),
+        Outcome::Expression(poly) => polynomial(poly),
+        Outcome::Fraction(frac) => fraction(frac),
+        Outcome::Boolean(res) => format!("{}", res),
+        Outcome::EquationSolved(sol) => solution(sol),
+        Outcome::SystemSolved(sys) => system(sys),
+        Outcome::MemoryDump(var, fun) => memory(var, fun),
+        Outcome::Settings(verbose, bench, explain) => format!(
+            "{{\"verbose\":{},\"benchmark\":{},\"explain\":{}}}",
+            verbose, bench, explain
+        ),
+    }
+}
+
+fn memory(var: &[Variable], fun: &[String]) -> String {
+    let var_lst: Vec<String> = var
+        .iter()
+        .map(|v| {
+            format!(
+                "{{\"name\":{},\"value\":{}}}",
+                string(&v.name()),
+                value(&v.val())
+            )
+        })
+        .collect();
+    let fun_lst: Vec<String> = fun.iter().map(|f| string(f)).collect();
+    format!(
+        "{{\"variables\":[{}],\"functions\":[{}]}}",
+        var_lst.join(","),
+        fun_lst.join(",")
+    )
+}
+
+fn solution(sol: &Solution) -> String {
+    let mut pows: Vec<&i32> = sol.reduced.keys().collect();
+    pows.sort();
+    let reduced: Vec<String> = pows
+        .iter()
+        .map(|pow| {
+            format!(
+                "{{\"pow\":{},\"coef\":{}}}",
+                pow,
+                imaginary(&sol.reduced[pow])
+            )
+        })
+        .collect();
+    let discriminant = match &sol.discriminant {
+        Some(delta) => imaginary(delta),
+        None => String::from("null"),
+    };
+    let roots: Vec<String> = sol.roots.iter().map(imaginary).collect();
+    let surds: Vec<String> = (0..sol.roots.len())
+        .map(|pos| match sol.surds.get(pos) {
+            Some(Some(surd)) => string(&surd.to_string()),
+            _ => String::from("null"),
+        })
+        .collect();
+    let excluded: Vec<String> = sol.excluded.iter().map(imaginary).collect();
+    let steps: Vec<String> = sol.steps.iter().map(|s| string(s)).collect();
+    let approximation = match &sol.approximation {
+        Some(approx) => format!(
+            "{{\"multiplicities\":[{}],\"iterations\":{},\"residual\":{}}}",
+            approx
+                .multiplicities
+                .iter()
+                .map(|count| count.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            approx.iterations,
+            float(approx.residual)
+        ),
+        None => String::from("null"),
+    };
+    format!(
+        "{{\"unknown\":{},\"degree\":{},\"reduced\":[{}],\"discriminant\":{},\"roots\":[{}],\"exact\":[{}],\"approximation\":{},\"excluded\":[{}],\"steps\":[{}]}}",
+        string(&sol.id),
+        sol.degree,
+        reduced.join(","),
+        discriminant,
+        roots.join(","),
+        surds.join(","),
+        approximation,
+        excluded.join(","),
+        steps.join(",")
+    )
+}
+
+fn float(val: f64) -> String {
+    if val.is_finite() {
+        format!("{:e}", val)
+    } else {
+        String::from("null")
+    }
+}
+
+fn system(sys: &System) -> String {
+    let unknowns: Vec<String> =
+        sys.unknowns.iter().map(|id| string(id)).collect();
+    let (status, solution) = match &sys.solution {
+        None => ("inconsistent", String::from("null")),
+        Some(values) => {
+            let lst: Vec<String> = sys
+                .unknowns
+                .iter()
+                .zip(values.iter())
+                .map(|(id, val)| {
+                    let terms: Vec<String> = val
+                        .terms
+                        .iter()
+                        .map(|(free, coef)| {
+                            format!(
+                                "{{\"unknown\":{},\"coef\":{}}}",
+                                string(free),
+                                imaginary(coef)
+                            )
+                        })
+                        .collect();
+                    format!(
+                        "{{\"unknown\":{},\"constant\":{},\"terms\":[{}]}}",
+                        string(id),
+                        imaginary(&val.constant),
+                        terms.join(",")
+                    )
+                })
+                .collect();
+            let status = match sys.free().is_empty() {
+                true => "unique",
+                false => "parametric",
+            };
+            (status, format!("[{}]", lst.join(",")))
+        }
+    };
+    format!(
+        "{{\"unknowns\":[{}],\"status\":\"{}\",\"solution\":{}}}",
+        unknowns.join(","),
+        status,
+        solution
+    )
+}
+
+pub fn value(val: &Value) -> String {
+    match val {
+        Value::Im(im) => imaginary(im),
+        Value::Mat(mat) => matrix(mat),
+        Value::Poly(poly) => polynomial(poly),
+    }
+}
+
+pub fn rational(val: &Rational) -> String {
+    format!(
+        "{{\"num\":\"{}\",\"den\":\"{}\",\"sign\":{}}}",
+        val.num(),
+        val.den(),
+        if val.is_positive() { 1 } else { -1 }
+    )
+}
+
+pub fn imaginary(val: &Imaginary) -> String {
+    format!(
+        "{{\"type\":\"imaginary\",\"real\":{},\"irreal\":{}}}",
+        rational(&val.get_real()),
+        rational(&val.get_irreal())
+    )
+}
+
+pub fn polynomial(val: &Polynomial) -> String {
+    let unknowns: Vec<String> =
+        val.unknowns().iter().map(|id| string(id)).collect();
+    let terms: Vec<String> = val
+        .terms()
+        .iter()
+        .map(|(monomial, coef)| {
+            let powers: Vec<String> = monomial
+                .iter()
+                .map(|(id, pow)| format!("{}:{}", string(id), pow))
+                .collect();
+            format!(
+                "{{\"monomial\":{{{}}},\"coef\":{}}}",
+                powers.join(","),
+                imaginary(coef)
+            )
+        })
+        .collect();
+    format!(
+        "{{\"type\":\"polynomial\",\"unknowns\":[{}],\"terms\":[{}]}}",
+        unknowns.join(","),
+        terms.join(",")
+    )
+}
+
+fn fraction(val: &Fraction) -> String {
+    let poles: Vec<String> = val.poles().iter().map(polynomial).collect();
+    format!(
+        "{{\"type\":\"fraction\",\"numerator\":{},\"denominator\":{},\"poles\":[{}]}}",
+        polynomial(val.num()),
+        polynomial(val.den()),
+        poles.join(",")
+    )
+}
+
+pub fn matrix(val: &Matrix) -> String {
+    let data: Vec<String> = val.data().iter().map(imaginary).collect();
+    format!(
+        "{{\"type\":\"matrix\",\"width\":{},\"height\":{},\"data\":[{}]}}",
+        val.width(),
+        val.height(),
+        data.join(",")
+    )
+}
+
+fn string(raw: &str) -> String {
+    let mut escaped = String::from("\"");
+    for ch in raw.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                escaped.push_str(&format!("\\u{:04x}", ch as u32))
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod exact {
+    use super::{imaginary, polynomial, rational};
+    use crate::types::{Imaginary, Natural, Polynomial, Rational};
+
+    fn field<'a>(json: &'a str, key: &str) -> &'a str {
+        let start =
+            json.find(&format!("\"{}\":", key)).unwrap() + key.len() + 3;
+        let rest = &json[start..];
+        let end = rest.find([',', '}']).unwrap();
+        rest[..end].trim_matches('"')
+    }
+
+    fn decode(json: &str) -> Rational {
+        Rational::from_natural(
+            field(json, "sign") == "1",
+            Natural::parse(field(json, "num")).unwrap(),
+            Natural::parse(field(json, "den")).unwrap(),
+        )
+    }
+
+    #[test]
+    fn rational_round_trip() {
+        let tenth = Rational::parse("0.1").unwrap();
+        assert_eq!(
+            rational(&tenth),
+            "{\"num\":\"1\",\"den\":\"10\",\"sign\":1}"
+        );
+
+        let big = Natural::from_u64(2).pow(100);
+        let values = [
+            Rational::zero(),
+            tenth,
+            -Rational::new(2.0).div(&Rational::new(3.0)).unwrap(),
+            Rational::from_natural(true, big, Natural::from_u64(3)),
+            Rational::parse("1.5e-30").unwrap(),
+        ];
+        for val in values.iter() {
+            assert_eq!(decode(&rational(val)), *val);
+        }
+    }
+
+    #[test]
+    fn imaginary_round_trip() {
+        let val = Imaginary::from_parts(
+            Rational::parse("0.3").unwrap(),
+            -Rational::new(1.0).div(&Rational::new(7.0)).unwrap(),
+        );
+        let json = imaginary(&val);
+        let irreal = &json[json.find("\"irreal\"").unwrap()..];
+
+        assert_eq!(decode(&json), val.get_real());
+        assert_eq!(decode(irreal), val.get_irreal());
+    }
+
+    #[test]
+    fn polynomial_terms() {
+        let third = Imaginary::from_parts(
+            Rational::new(1.0).div(&Rational::new(3.0)).unwrap(),
+            Rational::zero(),
+        );
+        let x = Polynomial::unknown("x");
+        let poly = x
+            .mul(&x)
+            .unwrap()
+            .mul(&Polynomial::constant(third.clone()))
+            .unwrap();
+        let json = polynomial(&poly);
+
+        assert!(json.contains("\"monomial\":{\"x\":2}"));
+        assert_eq!(decode(&json), third.get_real());
+    }
+}
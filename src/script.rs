@@ -0,0 +1,125 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   script.rs                                          :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use computorv2::{
+    render, ComputorError, ErrorKind, Outcome, Output, Param, Session,
+};
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+pub struct Runner {
+    session: Session,
+    verbose: bool,
+    fail_fast: bool,
+    output: Output,
+    last_error: Option<ErrorKind>,
+}
+
+impl Runner {
+    pub fn new(param: &Param) -> Self {
+        Runner {
+            session: Session::with_param(param),
+            verbose: param.verbose(),
+            fail_fast: param.fail_fast(),
+            output: param.output(),
+            last_error: None,
+        }
+    }
+
+    pub fn set_logger<F: FnMut(&str) + 'static>(&mut self, logger: F) {
+        self.session.set_logger(logger);
+    }
+
+    pub fn eval(&mut self, input: &str, origin: Option<(&str, usize)>) -> bool {
+        let res = self.session.eval(input);
+        if let Ok(Outcome::Settings(verb, _, _)) = &res {
+            self.verbose = *verb;
+        }
+        if let Err(err) = &res {
+            self.last_error = Some(err.kind().clone());
+        }
+        match (self.output, &res) {
+            (Output::Json, _) => {
+                println!("{}", render::json::result(input, origin, &res))
+            }
+            (Output::Text, Ok(Outcome::Settings(_, _, _))) => {}
+            (Output::Text, Ok(outcome)) => {
+                println!("{}", render::outcome(outcome, self.verbose))
+            }
+            (Output::Text, Err(err)) => match origin {
+                Some((name, line)) => eprintln!("{}:{}: {}", name, line, err),
+                None => eprintln!("{}", err),
+            },
+        }
+        res.is_ok()
+    }
+
+    pub fn run_file(&mut self, path: &str) -> bool {
+        match File::open(path) {
+            Ok(file) => self.run_script(path, BufReader::new(file)),
+            Err(err) => {
+                self.io_error(path, 0, ComputorError::io(&err.to_string()));
+                false
+            }
+        }
+    }
+
+    pub fn run_script<R: BufRead>(&mut self, name: &str, input: R) -> bool {
+        for (i, line) in input.lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    let err = ComputorError::io(&err.to_string());
+                    self.io_error(name, i + 1, err);
+                    return false;
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if !self.eval(&line, Some((name, i + 1))) && self.fail_fast {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// `line` is 0 when the file couldn't be opened at all.
+    fn io_error(&mut self, name: &str, line: usize, err: ComputorError) {
+        self.last_error = Some(err.kind().clone());
+        match (self.output, line) {
+            (Output::Json, _) => println!(
+                "{}",
+                render::json::result("", Some((name, line)), &Err(err))
+            ),
+            (Output::Text, 0) => eprintln!("{}: {}", name, err),
+            (Output::Text, _) => eprintln!("{}:{}: {}", name, line, err),
+        }
+    }
+
+    pub fn run_evals(&mut self, evals: &[String]) -> bool {
+        for (i, instr) in evals.iter().enumerate() {
+            if !self.eval(instr, Some(("<eval>", i + 1))) && self.fail_fast {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match &self.last_error {
+            Some(kind) => kind.exit_code(),
+            None => 0,
+        }
+    }
+}
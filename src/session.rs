@@ -0,0 +1,178 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   session.rs                                         :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use crate::arg_parse::Param;
+use crate::computor::{Computor, ComputorError, EvalResult, Outcome};
+use crate::lexer::token::Resolve;
+use crate::lexer::{Lexer, Token};
+use crate::parser::Parser;
+
+type Logger = Box<dyn FnMut(&str)>;
+
+/// Evaluating nested function calls takes about 32 KiB of stack per level,
+/// so a thread running a `Session` needs `max_depth * 32 KiB` of stack on
+/// top of its base, about 8 MiB with the default depth of 256.
+pub struct Session {
+    lexer: Lexer,
+    parser: Parser,
+    computor: Computor,
+    logger: Option<Logger>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Session::with_param(&Param::default())
+    }
+
+    pub fn with_param(param: &Param) -> Self {
+        Session {
+            lexer: Lexer::new(param),
+            parser: Parser::new(param),
+            computor: Computor::new(param),
+            logger: None,
+        }
+    }
+
+    /// Verbose and benchmark lines are handed to `logger` as they are
+    /// produced, and dropped when no logger is set.
+    pub fn set_logger<F: FnMut(&str) + 'static>(&mut self, logger: F) {
+        self.logger = Some(Box::new(logger));
+    }
+
+    pub fn eval(&mut self, input: &str) -> EvalResult {
+        let res = self.eval_input(input);
+        self.flush_log();
+        res
+    }
+}
+
+impl Session {
+    fn eval_input(&mut self, input: &str) -> EvalResult {
+        let parts = split_system(input);
+        if parts.len() > 1 {
+            return self.eval_system(&parts);
+        }
+        let tokens = self.lexer.read_input(String::from(input));
+        self.flush_log();
+        let mut tokens = tokens?;
+        if tokens.is_empty() {
+            let verbose = self.lexer.verbose();
+            let bench = self.lexer.benchmark();
+            let explain = self.lexer.explain();
+            self.parser.update_param(verbose, bench);
+            self.computor.update_param(verbose, bench, explain);
+            return Ok(Outcome::Settings(verbose, bench, explain));
+        }
+        let solve = solve_mark(&mut tokens);
+        let tree = self.parser.parse_tokens(tokens);
+        self.flush_log();
+        match tree {
+            Some(tree) => self.computor.read_tokens(tree, solve),
+            None => Err(ComputorError::empty_instr()),
+        }
+    }
+
+    fn eval_system(&mut self, parts: &[&str]) -> EvalResult {
+        let mut trees = Vec::new();
+        for part in parts.iter().filter(|part| !part.trim().is_empty()) {
+            let tokens = self.lexer.read_input(String::from(*part));
+            self.flush_log();
+            let tree = self.parser.parse_tokens(tokens?);
+            self.flush_log();
+            match tree {
+                Some(tree) => trees.push(tree),
+                None => return Err(ComputorError::empty_instr()),
+            }
+        }
+        if trees.is_empty() {
+            return Err(ComputorError::empty_instr());
+        }
+        self.computor.read_system(trees)
+    }
+
+    fn flush_log(&mut self) {
+        let lines = self.lexer.drain_log().into_iter();
+        let lines = lines.chain(self.parser.drain_log());
+        let lines = lines.chain(self.computor.drain_log());
+        match self.logger.as_mut() {
+            Some(logger) => lines.for_each(|line| logger(&line)),
+            None => lines.for_each(drop),
+        }
+    }
+}
+
+/// Takes off the `?` ending an equation such as `p = 0 ?`, which asks to
+/// solve it rather than to assign `p`, while `p = ?` keeps its `?`.
+fn solve_mark(tokens: &mut Vec<Box<dyn Token>>) -> bool {
+    let is_equal =
+        |tok: &dyn Token| tok.as_op_ref().is_some_and(|op| op.symbol() == '=');
+    let marked = match tokens.as_slice() {
+        [.., before, last] => {
+            last.as_any().is::<Resolve>()
+                && !is_equal(before.as_ref())
+                && tokens.iter().any(|tok| is_equal(tok.as_ref()))
+        }
+        _ => false,
+    };
+    if marked {
+        tokens.pop();
+    }
+    marked
+}
+
+fn split_system(input: &str) -> Vec<&str> {
+    let mut parts: Vec<&str> = Vec::new();
+    let mut depth: i32 = 0;
+    let mut start: usize = 0;
+    for (pos, ch) in input.char_indices() {
+        match ch {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ';' if depth == 0 => {
+                parts.push(&input[start..pos]);
+                start = pos + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Session::new()
+    }
+}
+
+#[cfg(test)]
+mod logger {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn verbose_lines() {
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let mut session = Session::new();
+        let sink = Rc::clone(&lines);
+        session.set_logger(move |line| sink.borrow_mut().push(line.to_owned()));
+        session.eval("1 + 1").unwrap();
+        assert!(lines.borrow().is_empty());
+        session.eval("verbose").unwrap();
+        session.eval("1 + 1").unwrap();
+        let lines = lines.borrow();
+        assert!(lines[0].contains("[v:Lexer]"));
+        assert!(lines.iter().any(|line| line.contains("[v:Parser]")));
+        assert!(lines.last().unwrap().contains("[v:Computor]"));
+    }
+}
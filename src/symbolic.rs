@@ -0,0 +1,462 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   symbolic.rs                                        :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+mod derive;
+mod simplify;
+
+pub use derive::derive;
+pub use simplify::simplify;
+
+use crate::computor::ComputorError;
+use crate::lexer::token::{new_operator, FunctionTree, Value, Variable};
+use crate::lexer::Token;
+use crate::memory::{Memory, Value as Val};
+use crate::parser::{TokenTree, TreeBranch, TreeLeaf};
+use crate::types::{Imaginary, Natural, Polynomial, Rational};
+
+use std::collections::HashMap;
+
+pub const DIFF: &str = "diff";
+
+type TTree = Box<dyn TokenTree>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Num(Imaginary),
+    Var(String),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Mod(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+impl Expr {
+    pub fn from_tree(tree: &TTree) -> Result<Expr, ComputorError> {
+        if let Some(branch) = tree.as_any().downcast_ref::<TreeBranch>() {
+            let left = match branch.left() {
+                Some(left) => Some(Expr::from_tree(left)?),
+                None => None,
+            };
+            let right = match branch.right() {
+                Some(right) => Some(Expr::from_tree(right)?),
+                None => None,
+            };
+            return match (branch.op_ref().symbol(), left, right) {
+                ('+', Some(l), Some(r)) => Ok(add(l, r)),
+                ('+', None, Some(r)) => Ok(r),
+                ('-', Some(l), Some(r)) => Ok(sub(l, r)),
+                ('-', None, Some(r)) => Ok(neg(r)),
+                ('*', Some(l), Some(r)) => Ok(mul(l, r)),
+                ('/', Some(l), Some(r)) => Ok(div(l, r)),
+                ('%', Some(l), Some(r)) => Ok(modulo(l, r)),
+                ('^', Some(l), Some(r)) => Ok(pow(l, r)),
+                _ => Err(ComputorError::not_derivable(&format!("'{}'", tree))),
+            };
+        }
+        let token = tree.token().as_any();
+        if let Some(val) = token.downcast_ref::<Value>() {
+            return Ok(Expr::Num(val.value().clone()));
+        }
+        if let Some(var) = token.downcast_ref::<Variable>() {
+            return Ok(Expr::Var(var.id().clone()));
+        }
+        if let Some(fun) = token.downcast_ref::<FunctionTree>() {
+            let mut args: Vec<Expr> = Vec::new();
+            for param in fun.param().iter() {
+                args.push(Expr::from_tree(param)?);
+            }
+            return Ok(Expr::Call(fun.id().clone(), args));
+        }
+        Err(ComputorError::not_derivable(&format!("'{}'", tree)))
+    }
+
+    pub fn to_tree(&self) -> TTree {
+        match self {
+            Expr::Num(val) => num_tree(val),
+            Expr::Var(id) => match Variable::new(id.clone()) {
+                Ok(var) => leaf(Box::new(var)),
+                Err(err) => leaf(Box::new(err)),
+            },
+            Expr::Neg(val) => branch('-', None, Some(self.child(val, true))),
+            Expr::Add(l, r) => self.binary('+', l, r),
+            Expr::Sub(l, r) => self.binary('-', l, r),
+            Expr::Mul(l, r) => self.binary('*', l, r),
+            Expr::Div(l, r) => self.binary('/', l, r),
+            Expr::Mod(l, r) => self.binary('%', l, r),
+            Expr::Pow(l, r) => self.binary('^', l, r),
+            Expr::Call(id, args) => {
+                let param: Vec<TTree> =
+                    args.iter().map(Expr::to_tree).collect();
+                leaf(Box::new(FunctionTree::new(id.clone(), param)))
+            }
+        }
+    }
+
+    fn binary(&self, op: char, left: &Expr, right: &Expr) -> TTree {
+        branch(
+            op,
+            Some(self.child(left, false)),
+            Some(self.child(right, true)),
+        )
+    }
+
+    fn child(&self, expr: &Expr, right: bool) -> TTree {
+        let (parent, child) = (self.precedence(), expr.precedence());
+        let wrap = match self {
+            Expr::Neg(_) => child <= 1,
+            Expr::Pow(_, _) => child <= 3 || (right && child < 4),
+            Expr::Sub(_, _) | Expr::Div(_, _) | Expr::Mod(_, _) if right => {
+                child <= parent
+            }
+            _ => child < parent || (expr.is_signed() && right),
+        };
+        let mut tree = expr.to_tree();
+        if wrap {
+            tree.set_as_exp();
+        }
+        tree
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Num(val) => num_precedence(val),
+            Expr::Var(_) | Expr::Call(_, _) => 4,
+            Expr::Neg(_) | Expr::Add(_, _) | Expr::Sub(_, _) => 1,
+            Expr::Mul(_, _) | Expr::Div(_, _) | Expr::Mod(_, _) => 2,
+            Expr::Pow(_, _) => 3,
+        }
+    }
+
+    fn is_signed(&self) -> bool {
+        match self {
+            Expr::Neg(_) => true,
+            Expr::Num(val) => num_precedence(val) == 1,
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, var: &str) -> bool {
+        match self {
+            Expr::Num(_) => false,
+            Expr::Var(id) => id == var,
+            Expr::Neg(val) => val.contains(var),
+            Expr::Add(l, r)
+            | Expr::Sub(l, r)
+            | Expr::Mul(l, r)
+            | Expr::Div(l, r)
+            | Expr::Mod(l, r)
+            | Expr::Pow(l, r) => l.contains(var) || r.contains(var),
+            Expr::Call(_, args) => args.iter().any(|arg| arg.contains(var)),
+        }
+    }
+
+    pub fn from_poly(poly: &Polynomial) -> Expr {
+        let mut res: Option<Expr> = None;
+        for (monomial, coef) in poly.terms() {
+            let mut term = Expr::Num(coef.clone());
+            for (id, power) in monomial.iter() {
+                term = mul(
+                    term,
+                    match power {
+                        1 => Expr::Var(id.clone()),
+                        _ => pow(Expr::Var(id.clone()), num(*power as f64)),
+                    },
+                );
+            }
+            res = Some(match res {
+                None => term,
+                Some(prev) => add(prev, term),
+            });
+        }
+        res.unwrap_or(num(0.0))
+    }
+
+    pub fn expand_polys(&self, mem: &Memory) -> Expr {
+        let mut values: HashMap<String, Expr> = HashMap::new();
+        self.walk(&mut |expr| {
+            if let Expr::Var(id) = expr {
+                if let Some(Val::Poly(poly)) = mem.get_var(id).map(|v| v.val())
+                {
+                    values.insert(id.clone(), Expr::from_poly(&poly));
+                }
+            }
+        });
+        self.substitute(&values)
+    }
+
+    pub fn substitute(&self, values: &HashMap<String, Expr>) -> Expr {
+        let sub = |val: &Expr| Box::new(val.substitute(values));
+        match self {
+            Expr::Num(_) => self.clone(),
+            Expr::Var(id) => match values.get(id) {
+                Some(val) => val.clone(),
+                None => self.clone(),
+            },
+            Expr::Neg(val) => Expr::Neg(sub(val)),
+            Expr::Add(l, r) => Expr::Add(sub(l), sub(r)),
+            Expr::Sub(l, r) => Expr::Sub(sub(l), sub(r)),
+            Expr::Mul(l, r) => Expr::Mul(sub(l), sub(r)),
+            Expr::Div(l, r) => Expr::Div(sub(l), sub(r)),
+            Expr::Mod(l, r) => Expr::Mod(sub(l), sub(r)),
+            Expr::Pow(l, r) => Expr::Pow(sub(l), sub(r)),
+            Expr::Call(id, args) => Expr::Call(
+                id.clone(),
+                args.iter().map(|arg| arg.substitute(values)).collect(),
+            ),
+        }
+    }
+
+    pub fn bind(
+        &self,
+        mem: &Memory,
+        keep: &str,
+    ) -> Result<Expr, ComputorError> {
+        let mut values: HashMap<String, Expr> = HashMap::new();
+        self.walk(&mut |expr| {
+            if let Expr::Var(id) = expr {
+                if let (true, Some(var)) = (id != keep, mem.get_var(id)) {
+                    values.insert(
+                        id.clone(),
+                        match var.val() {
+                            Val::Im(val) => Expr::Num(val),
+                            Val::Mat(_) => Expr::Var(id.clone()),
+                            Val::Poly(poly) => Expr::from_poly(&poly),
+                        },
+                    );
+                }
+            }
+        });
+        if values.values().any(|val| matches!(val, Expr::Var(_))) {
+            return Err(ComputorError::not_derivable("a matrix"));
+        }
+        Ok(self.substitute(&values))
+    }
+
+    fn walk(&self, fun: &mut dyn FnMut(&Expr)) {
+        fun(self);
+        match self {
+            Expr::Num(_) | Expr::Var(_) => {}
+            Expr::Neg(val) => val.walk(fun),
+            Expr::Add(l, r)
+            | Expr::Sub(l, r)
+            | Expr::Mul(l, r)
+            | Expr::Div(l, r)
+            | Expr::Mod(l, r)
+            | Expr::Pow(l, r) => {
+                l.walk(fun);
+                r.walk(fun);
+            }
+            Expr::Call(_, args) => args.iter().for_each(|arg| arg.walk(fun)),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        if self.precedence() < 4 {
+            format!("({})", self.to_tree())
+        } else {
+            self.to_tree().to_string()
+        }
+    }
+}
+
+pub fn is_diff_call(tree: &TTree) -> bool {
+    match tree.as_any().downcast_ref::<TreeLeaf>() {
+        Some(_) => match tree.token().as_any().downcast_ref::<FunctionTree>() {
+            Some(fun) => fun.id() == DIFF,
+            None => false,
+        },
+        None => false,
+    }
+}
+
+fn has_diff(tree: &TTree) -> bool {
+    if let Some(branch) = tree.as_any().downcast_ref::<TreeBranch>() {
+        return branch.left().is_some_and(has_diff)
+            || branch.right().is_some_and(has_diff);
+    }
+    match tree.token().as_any().downcast_ref::<FunctionTree>() {
+        Some(fun) => fun.id() == DIFF || fun.param().iter().any(has_diff),
+        None => false,
+    }
+}
+
+pub fn expand_diff(tree: TTree, mem: &Memory) -> Result<TTree, ComputorError> {
+    if !has_diff(&tree) {
+        return Ok(tree);
+    }
+    let expr = expand(&Expr::from_tree(&tree)?, mem)?;
+    Ok(simplify(&expr).to_tree())
+}
+
+fn expand(expr: &Expr, mem: &Memory) -> Result<Expr, ComputorError> {
+    let sub = |val: &Expr| -> Result<Box<Expr>, ComputorError> {
+        Ok(Box::new(expand(val, mem)?))
+    };
+    Ok(match expr {
+        Expr::Num(_) | Expr::Var(_) => expr.clone(),
+        Expr::Neg(val) => Expr::Neg(sub(val)?),
+        Expr::Add(l, r) => Expr::Add(sub(l)?, sub(r)?),
+        Expr::Sub(l, r) => Expr::Sub(sub(l)?, sub(r)?),
+        Expr::Mul(l, r) => Expr::Mul(sub(l)?, sub(r)?),
+        Expr::Div(l, r) => Expr::Div(sub(l)?, sub(r)?),
+        Expr::Mod(l, r) => Expr::Mod(sub(l)?, sub(r)?),
+        Expr::Pow(l, r) => Expr::Pow(sub(l)?, sub(r)?),
+        Expr::Call(id, args) => {
+            let mut expanded: Vec<Expr> = Vec::new();
+            for arg in args.iter() {
+                expanded.push(expand(arg, mem)?);
+            }
+            if id == DIFF {
+                differentiate(&expanded, mem)?.2
+            } else {
+                Expr::Call(id.clone(), expanded)
+            }
+        }
+    })
+}
+
+pub fn differentiate(
+    args: &[Expr],
+    mem: &Memory,
+) -> Result<(Expr, String, Expr), ComputorError> {
+    let var = match args {
+        [_, Expr::Var(var)] => var.clone(),
+        _ => return Err(ComputorError::fun_arg_inv(&String::from(DIFF))),
+    };
+    let target = match &args[0] {
+        Expr::Var(id) if mem.get_var(id).is_none() => match mem.get_fun(id) {
+            Some(fun) => {
+                let params = fun.vars().iter();
+                Expr::Call(
+                    id.clone(),
+                    params.map(|p| Expr::Var(p.clone())).collect(),
+                )
+            }
+            None => args[0].clone(),
+        },
+        target => target.clone(),
+    };
+    let res = simplify(&derive(&target.expand_polys(mem), &var, mem)?);
+    Ok((target, var, res))
+}
+
+fn num_tree(val: &Imaginary) -> TTree {
+    let real = val.get_real();
+    let irreal = val.get_irreal();
+    if val.is_real() {
+        return rational_tree(&real, false);
+    }
+    if real.is_zero() {
+        return rational_tree(&irreal, true);
+    }
+    let op = if irreal.is_positive() { '+' } else { '-' };
+    branch(
+        op,
+        Some(rational_tree(&real, false)),
+        Some(rational_tree(&irreal.abs(), true)),
+    )
+}
+
+fn rational_tree(val: &Rational, irreal: bool) -> TTree {
+    let num =
+        Rational::from_natural(true, val.num().clone(), Natural::from_u64(1));
+    let num = if irreal {
+        Imaginary::from_parts(Rational::zero(), num)
+    } else {
+        Imaginary::from_parts(num, Rational::zero())
+    };
+    let mut tree = leaf(Box::new(Value::from(num)));
+    if !val.is_int() {
+        let den = Rational::from_natural(
+            true,
+            val.den().clone(),
+            Natural::from_u64(1),
+        );
+        let den = Imaginary::from_parts(den, Rational::zero());
+        tree = branch('/', Some(tree), Some(leaf(Box::new(Value::from(den)))));
+    }
+    if !val.is_positive() {
+        if !val.is_int() {
+            tree.set_as_exp();
+        }
+        tree = branch('-', None, Some(tree));
+    }
+    tree
+}
+
+fn num_precedence(val: &Imaginary) -> u8 {
+    let real = val.get_real();
+    let irreal = val.get_irreal();
+    if !val.is_real() && !real.is_zero() {
+        return 1;
+    }
+    let part = if val.is_real() { real } else { irreal };
+    if !part.is_positive() {
+        1
+    } else if !part.is_int() {
+        2
+    } else {
+        4
+    }
+}
+
+fn leaf(token: Box<dyn Token>) -> TTree {
+    Box::new(TreeLeaf::new(token))
+}
+
+fn branch(op: char, left: Option<TTree>, right: Option<TTree>) -> TTree {
+    let token = match new_operator(op) {
+        Ok(token) => token,
+        Err(err) => Box::new(err),
+    };
+    Box::new(TreeBranch::from_parts(token, left, right))
+}
+
+fn num(val: f64) -> Expr {
+    Expr::Num(Imaginary::new(val, 0.0))
+}
+
+fn neg(val: Expr) -> Expr {
+    Expr::Neg(Box::new(val))
+}
+
+fn add(left: Expr, right: Expr) -> Expr {
+    Expr::Add(Box::new(left), Box::new(right))
+}
+
+fn sub(left: Expr, right: Expr) -> Expr {
+    Expr::Sub(Box::new(left), Box::new(right))
+}
+
+fn mul(left: Expr, right: Expr) -> Expr {
+    Expr::Mul(Box::new(left), Box::new(right))
+}
+
+fn div(left: Expr, right: Expr) -> Expr {
+    Expr::Div(Box::new(left), Box::new(right))
+}
+
+fn modulo(left: Expr, right: Expr) -> Expr {
+    Expr::Mod(Box::new(left), Box::new(right))
+}
+
+fn pow(left: Expr, right: Expr) -> Expr {
+    Expr::Pow(Box::new(left), Box::new(right))
+}
+
+fn call(id: &str, arg: Expr) -> Expr {
+    Expr::Call(String::from(id), vec![arg])
+}
@@ -0,0 +1,200 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   derive.rs                                          :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{add, call, div, mul, neg, num, pow, sub, Expr, DIFF};
+
+use crate::computor::ComputorError;
+use crate::memory::{builtin, Memory};
+
+use std::collections::HashMap;
+
+pub fn derive(
+    expr: &Expr,
+    var: &str,
+    mem: &Memory,
+) -> Result<Expr, ComputorError> {
+    if !expr.contains(var) {
+        return Ok(num(0.0));
+    }
+    let d = |val: &Expr| derive(val, var, mem);
+    Ok(match expr {
+        Expr::Num(_) => num(0.0),
+        Expr::Var(_) => num(1.0),
+        Expr::Neg(val) => neg(d(val)?),
+        Expr::Add(l, r) => add(d(l)?, d(r)?),
+        Expr::Sub(l, r) => sub(d(l)?, d(r)?),
+        Expr::Mul(l, r) => add(mul(d(l)?, *r.clone()), mul(*l.clone(), d(r)?)),
+        Expr::Div(l, r) => div(
+            sub(mul(d(l)?, *r.clone()), mul(*l.clone(), d(r)?)),
+            pow(*r.clone(), num(2.0)),
+        ),
+        Expr::Mod(_, _) => return Err(ComputorError::not_derivable("'%'")),
+        Expr::Pow(base, exp) => {
+            let (base, exp) = (*base.clone(), *exp.clone());
+            if !exp.contains(var) {
+                let lower = pow(base.clone(), sub(exp.clone(), num(1.0)));
+                mul(mul(exp, lower), d(&base)?)
+            } else if !base.contains(var) {
+                mul(
+                    mul(pow(base.clone(), exp.clone()), call("ln", base)),
+                    d(&exp)?,
+                )
+            } else {
+                let ln_part = mul(d(&exp)?, call("ln", base.clone()));
+                let pow_part = div(mul(exp.clone(), d(&base)?), base.clone());
+                mul(pow(base, exp), add(ln_part, pow_part))
+            }
+        }
+        Expr::Call(id, args) => derive_call(id, args, var, mem)?,
+    })
+}
+
+fn derive_call(
+    id: &String,
+    args: &[Expr],
+    var: &str,
+    mem: &Memory,
+) -> Result<Expr, ComputorError> {
+    if id == DIFF {
+        let (_, _, inner) = super::differentiate(args, mem)?;
+        return derive(&inner, var, mem);
+    }
+    if builtin::get(id).is_some() {
+        if args.len() != 1 {
+            return Err(ComputorError::fun_arg_inv(id));
+        }
+        let outer = match outer_derivative(id, &args[0]) {
+            Some(outer) => outer,
+            None => return Ok(num(0.0)),
+        };
+        return Ok(mul(outer, derive(&args[0], var, mem)?));
+    }
+    let fun = match mem.get_fun(id) {
+        Some(fun) => fun,
+        None => return Err(ComputorError::fun_undef(id)),
+    };
+    let body = match fun.expr() {
+        Some(body) => Expr::from_tree(body)?,
+        None => return Err(ComputorError::fun_undef(id)),
+    };
+    if fun.vars().len() != args.len() {
+        return Err(ComputorError::fun_arg_inv(id));
+    }
+    let values: HashMap<String, Expr> = fun
+        .vars()
+        .iter()
+        .cloned()
+        .zip(args.iter().cloned())
+        .collect();
+    mem.enter_call()?;
+    let res = derive(&body.substitute(&values).expand_polys(mem), var, mem);
+    mem.leave_call();
+    res
+}
+
+fn outer_derivative(id: &str, u: &Expr) -> Option<Expr> {
+    let u = u.clone();
+    let square = pow(u.clone(), num(2.0));
+    Some(match id {
+        "sqrt" => div(num(1.0), mul(num(2.0), call("sqrt", u))),
+        "cbrt" => div(num(1.0), mul(num(3.0), pow(call("cbrt", u), num(2.0)))),
+        "exp" => call("exp", u),
+        "ln" => div(num(1.0), u),
+        "log" => div(num(1.0), mul(u, call("ln", num(10.0)))),
+        "sin" => call("cos", u),
+        "cos" => neg(call("sin", u)),
+        "tan" => div(num(1.0), pow(call("cos", u), num(2.0))),
+        "asin" => div(num(1.0), call("sqrt", sub(num(1.0), square))),
+        "acos" => neg(div(num(1.0), call("sqrt", sub(num(1.0), square)))),
+        "atan" => div(num(1.0), add(num(1.0), square)),
+        "sinh" => call("cosh", u),
+        "cosh" => call("sinh", u),
+        "tanh" => div(num(1.0), pow(call("cosh", u), num(2.0))),
+        "asinh" => div(num(1.0), call("sqrt", add(square, num(1.0)))),
+        "acosh" => div(
+            num(1.0),
+            mul(
+                call("sqrt", sub(u.clone(), num(1.0))),
+                call("sqrt", add(u, num(1.0))),
+            ),
+        ),
+        "atanh" => div(num(1.0), sub(num(1.0), square)),
+        "abs" => div(u.clone(), call("abs", u)),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod rules {
+    use super::derive;
+    use crate::computor::Outcome;
+    use crate::memory::Memory;
+    use crate::session::Session;
+    use crate::symbolic::{
+        add, call, div, modulo, mul, num, pow, simplify, Expr,
+    };
+
+    fn x() -> Expr {
+        Expr::Var(String::from("x"))
+    }
+
+    fn diff(expr: &Expr) -> String {
+        let mem = Memory::new(16);
+        simplify(&derive(expr, "x", &mem).unwrap())
+            .to_tree()
+            .to_string()
+    }
+
+    #[test]
+    fn polynomial() {
+        assert_eq!(diff(&num(3.0)), "0");
+        assert_eq!(diff(&Expr::Var(String::from("y"))), "0");
+        let cubic = add(pow(x(), num(3.0)), mul(num(2.0), x()));
+        assert_eq!(diff(&cubic), "3*x^2+2");
+    }
+
+    #[test]
+    fn product_and_quotient() {
+        assert_eq!(diff(&mul(x(), x())), "2*x");
+        assert_eq!(diff(&div(x(), add(x(), num(1.0)))), "1/(x+1)^2");
+    }
+
+    #[test]
+    fn chain_rule() {
+        assert_eq!(diff(&call("sin", mul(num(2.0), x()))), "2*cos(2*x)");
+        assert_eq!(diff(&call("ln", x())), "1/x");
+        assert_eq!(diff(&pow(num(2.0), x())), "2^x*ln(2)");
+    }
+
+    #[test]
+    fn other_variable() {
+        let mut session = Session::new();
+        session.eval("f(x) = x^2").unwrap();
+
+        match session.eval("diff(f, y)") {
+            Ok(Outcome::Derivative(target, var, res)) => {
+                assert_eq!((target.as_str(), var.as_str()), ("f(x)", "y"));
+                assert_eq!(res, "0");
+            }
+            _ => panic!("not derived"),
+        }
+    }
+
+    #[test]
+    fn not_derivable() {
+        let mem = Memory::new(16);
+
+        assert!(derive(&modulo(x(), num(2.0)), "x", &mem).is_err());
+        let unknown = call("f", x());
+        assert!(derive(&unknown, "x", &mem).is_err());
+    }
+}
@@ -0,0 +1,380 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   simplify.rs                                        :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{add, div, mul, neg, num, pow, sub, Expr};
+
+use crate::computor::Computed;
+use crate::memory::{builtin, Value};
+use crate::types::Imaginary;
+
+const MAX_PASS: usize = 64;
+
+const EXACT_AT: &[(&str, f64)] = &[
+    ("exp", 0.0),
+    ("ln", 1.0),
+    ("sin", 0.0),
+    ("cos", 0.0),
+    ("tan", 0.0),
+    ("asin", 0.0),
+    ("acos", 1.0),
+    ("atan", 0.0),
+    ("sinh", 0.0),
+    ("cosh", 0.0),
+    ("tanh", 0.0),
+    ("asinh", 0.0),
+    ("acosh", 1.0),
+    ("atanh", 0.0),
+];
+
+pub fn simplify(expr: &Expr) -> Expr {
+    let mut current = expr.clone();
+    for _ in 0..MAX_PASS {
+        let next = pass(&current);
+        if next == current {
+            break;
+        }
+        current = next;
+    }
+    current
+}
+
+fn pass(expr: &Expr) -> Expr {
+    match expr {
+        Expr::Num(_) | Expr::Var(_) => expr.clone(),
+        Expr::Neg(val) => neg_rule(pass(val)),
+        Expr::Add(l, r) => collect(add_rule(pass(l), pass(r))),
+        Expr::Sub(l, r) => collect(sub_rule(pass(l), pass(r))),
+        Expr::Mul(l, r) => mul_rule(pass(l), pass(r)),
+        Expr::Div(l, r) => div_rule(pass(l), pass(r)),
+        Expr::Mod(l, r) => Expr::Mod(Box::new(pass(l)), Box::new(pass(r))),
+        Expr::Pow(l, r) => pow_rule(pass(l), pass(r)),
+        Expr::Call(id, args) => call_rule(id, args.iter().map(pass).collect()),
+    }
+}
+
+fn value(expr: &Expr) -> Option<&Imaginary> {
+    match expr {
+        Expr::Num(val) => Some(val),
+        _ => None,
+    }
+}
+
+fn is(expr: &Expr, target: f64) -> bool {
+    value(expr).is_some_and(|val| *val == Imaginary::new(target, 0.0))
+}
+
+fn is_negative(expr: &Expr) -> bool {
+    value(expr)
+        .is_some_and(|val| val.is_real() && !val.get_real().is_positive())
+}
+
+fn integer(expr: &Expr) -> Option<i32> {
+    let val = value(expr)?;
+    let real = val.get_real();
+    if !val.is_real() || !real.is_int() || real.bits() > 31 {
+        return None;
+    }
+    Some(real.get_val() as i32)
+}
+
+fn split_coef(expr: &Expr) -> (Imaginary, Expr) {
+    match expr {
+        Expr::Mul(l, r) if value(l).is_some() => {
+            (value(l).unwrap().clone(), *r.clone())
+        }
+        _ => (Imaginary::new(1.0, 0.0), expr.clone()),
+    }
+}
+
+fn terms(expr: &Expr, negative: bool, out: &mut Vec<(Imaginary, Expr)>) {
+    match expr {
+        Expr::Add(l, r) => {
+            terms(l, negative, out);
+            terms(r, negative, out);
+        }
+        Expr::Sub(l, r) => {
+            terms(l, negative, out);
+            terms(r, !negative, out);
+        }
+        Expr::Neg(val) => terms(val, !negative, out),
+        _ => {
+            let (coef, rest) = match value(expr) {
+                Some(val) => (val.clone(), num(1.0)),
+                None => split_coef(expr),
+            };
+            out.push((if negative { -coef } else { coef }, rest));
+        }
+    }
+}
+
+fn collect(expr: Expr) -> Expr {
+    if !matches!(expr, Expr::Add(_, _) | Expr::Sub(_, _)) {
+        return expr;
+    }
+    let mut all = Vec::new();
+    terms(&expr, false, &mut all);
+    let mut like: Vec<(Imaginary, Expr)> = Vec::new();
+    for (coef, rest) in all.iter() {
+        match like.iter_mut().find(|(_, other)| other == rest) {
+            Some((sum, _)) => match sum.add(coef) {
+                Ok(res) => *sum = res,
+                Err(_) => return expr,
+            },
+            None => like.push((coef.clone(), rest.clone())),
+        }
+    }
+    if like.len() == all.len() {
+        return expr;
+    }
+    let zero = Imaginary::new(0.0, 0.0);
+    let mut res: Option<Expr> = None;
+    for (coef, rest) in like.into_iter().filter(|(coef, _)| *coef != zero) {
+        let coef = Expr::Num(coef);
+        res = Some(match res {
+            None => mul_rule(coef, rest),
+            Some(prev) if is_negative(&coef) => {
+                sub(prev, mul_rule(neg_rule(coef), rest))
+            }
+            Some(prev) => add(prev, mul_rule(coef, rest)),
+        });
+    }
+    res.unwrap_or(num(0.0))
+}
+
+fn neg_rule(val: Expr) -> Expr {
+    match val {
+        Expr::Num(val) => Expr::Num(-val),
+        Expr::Neg(val) => *val,
+        val => neg(val),
+    }
+}
+
+fn add_rule(l: Expr, r: Expr) -> Expr {
+    if let (Some(a), Some(b)) = (value(&l), value(&r)) {
+        if let Ok(res) = a.add(b) {
+            return Expr::Num(res);
+        }
+    }
+    if is(&l, 0.0) {
+        return r;
+    }
+    if is(&r, 0.0) {
+        return l;
+    }
+    match (l, r) {
+        (l, Expr::Neg(r)) => sub(l, *r),
+        (l, r) if is_negative(&r) => sub(l, neg_rule(r)),
+        (Expr::Neg(l), r) => sub(r, *l),
+        (l, r) if value(&l).is_some() => add(r, l),
+        (Expr::Add(x, a), b) if value(&a).is_some() && value(&b).is_some() => {
+            add(*x, add_rule(*a, b))
+        }
+        (l, r) => {
+            let (a, x) = split_coef(&l);
+            let (b, y) = split_coef(&r);
+            match (x == y, a.add(&b)) {
+                (true, Ok(coef)) => mul_rule(Expr::Num(coef), x),
+                _ => add(l, r),
+            }
+        }
+    }
+}
+
+fn sub_rule(l: Expr, r: Expr) -> Expr {
+    if let (Some(a), Some(b)) = (value(&l), value(&r)) {
+        if let Ok(res) = a.sub(b) {
+            return Expr::Num(res);
+        }
+    }
+    if is(&r, 0.0) {
+        return l;
+    }
+    if is(&l, 0.0) {
+        return neg_rule(r);
+    }
+    match (l, r) {
+        (l, Expr::Neg(r)) => add(l, *r),
+        (l, r) if is_negative(&r) => add(l, neg_rule(r)),
+        (l, r) => {
+            let (a, x) = split_coef(&l);
+            let (b, y) = split_coef(&r);
+            match (x == y, a.sub(&b)) {
+                (true, Ok(coef)) => mul_rule(Expr::Num(coef), x),
+                _ => sub(l, r),
+            }
+        }
+    }
+}
+
+fn mul_rule(l: Expr, r: Expr) -> Expr {
+    if let (Some(a), Some(b)) = (value(&l), value(&r)) {
+        if let Ok(res) = a.mul(b) {
+            return Expr::Num(res);
+        }
+    }
+    if is(&l, 0.0) || is(&r, 0.0) {
+        return num(0.0);
+    }
+    if is(&l, 1.0) {
+        return r;
+    }
+    if is(&r, 1.0) {
+        return l;
+    }
+    if is(&l, -1.0) {
+        return neg_rule(r);
+    }
+    if is(&r, -1.0) {
+        return neg_rule(l);
+    }
+    match (l, r) {
+        (l, r) if is_negative(&l) => neg(mul_rule(neg_rule(l), r)),
+        (Expr::Neg(l), r) => neg(mul(*l, r)),
+        (l, Expr::Neg(r)) => neg(mul(l, *r)),
+        (l, r) if value(&r).is_some() => mul(r, l),
+        (l, Expr::Mul(a, x)) if value(&l).is_some() && value(&a).is_some() => {
+            mul(mul_rule(l, *a), *x)
+        }
+        (Expr::Mul(a, x), y) if value(&a).is_some() => mul(*a, mul(*x, y)),
+        (x, Expr::Mul(a, y)) if value(&a).is_some() && value(&x).is_none() => {
+            mul(*a, mul(x, *y))
+        }
+        (Expr::Div(a, b), c) => div(mul(*a, c), *b),
+        (c, Expr::Div(a, b)) => div(mul(c, *a), *b),
+        (l, r) if l == r => pow(l, num(2.0)),
+        (Expr::Pow(x, a), y) if *x == y && value(&a).is_some() => {
+            pow(y, add_rule(*a, num(1.0)))
+        }
+        (x, Expr::Pow(y, a)) if x == *y && value(&a).is_some() => {
+            pow(x, add_rule(*a, num(1.0)))
+        }
+        (Expr::Pow(x, a), Expr::Pow(y, b))
+            if x == y && value(&a).is_some() && value(&b).is_some() =>
+        {
+            pow(*x, add_rule(*a, *b))
+        }
+        (l, r) => mul(l, r),
+    }
+}
+
+fn div_rule(l: Expr, r: Expr) -> Expr {
+    if is(&r, 0.0) {
+        return div(l, r);
+    }
+    if let (Some(a), Some(b)) = (value(&l), value(&r)) {
+        if let Ok(res) = a.div(b) {
+            return Expr::Num(res);
+        }
+    }
+    if is(&l, 0.0) {
+        return num(0.0);
+    }
+    if is(&r, 1.0) {
+        return l;
+    }
+    if is(&r, -1.0) {
+        return neg_rule(l);
+    }
+    match (l, r) {
+        (l, r) if l == r => num(1.0),
+        (l, r) if is_negative(&l) => neg(div_rule(neg_rule(l), r)),
+        (Expr::Neg(l), r) => neg(div(*l, r)),
+        (l, Expr::Neg(r)) => neg(div(l, *r)),
+        (Expr::Div(a, b), c) => div(*a, mul(*b, c)),
+        (a, Expr::Div(b, c)) => div(mul(a, *c), *b),
+        (Expr::Mul(a, x), b) if value(&a).is_some() && value(&b).is_some() => {
+            mul(div_rule(*a, b), *x)
+        }
+        (l, Expr::Mul(b, y)) if value(&b).is_some() => {
+            let (a, x) = split_coef(&l);
+            div(mul_rule(div_rule(Expr::Num(a), *b), x), *y)
+        }
+        (Expr::Pow(x, a), y) if *x == y && value(&a).is_some() => {
+            pow(y, sub_rule(*a, num(1.0)))
+        }
+        (l, r) => div(l, r),
+    }
+}
+
+fn pow_rule(base: Expr, exp: Expr) -> Expr {
+    if is(&exp, 0.0) {
+        return num(1.0);
+    }
+    if is(&exp, 1.0) || is(&base, 1.0) {
+        return base;
+    }
+    if let (Some(val), Some(n)) = (value(&base), integer(&exp)) {
+        if let Ok(res) = val.pow(n) {
+            return Expr::Num(res);
+        }
+    }
+    if let Some(n) = integer(&exp) {
+        if n < 0 {
+            return div(num(1.0), pow(base, num(-n as f64)));
+        }
+    }
+    match (base, exp) {
+        (Expr::Pow(x, a), b)
+            if integer(&a).is_some() && integer(&b).is_some() =>
+        {
+            pow(*x, mul_rule(*a, b))
+        }
+        (base, exp) => pow(base, exp),
+    }
+}
+
+fn call_rule(id: &String, args: Vec<Expr>) -> Expr {
+    if let ([Expr::Num(val)], Some(fun)) = (&args[..], builtin::get(id)) {
+        let exact = match id.as_str() {
+            "sqrt" => builtin::exact_sqrt(val).is_some(),
+            "cbrt" => val.is_real() && val.get_real().exact_root(3).is_some(),
+            "abs" | "floor" | "ceil" | "round" => val.is_real(),
+            _ => EXACT_AT.iter().any(|(name, at)| {
+                name == id && *val == Imaginary::new(*at, 0.0)
+            }),
+        };
+        if exact {
+            if let Ok(Computed::ValIm(res)) =
+                fun.compute(vec![Value::Im(val.clone())])
+            {
+                return Expr::Num(res);
+            }
+        }
+    }
+    Expr::Call(id.clone(), args)
+}
+
+#[cfg(test)]
+mod like_terms {
+    use super::simplify;
+    use crate::symbolic::{add, div, mul, num, pow, sub, Expr};
+
+    fn x() -> Expr {
+        Expr::Var(String::from("x"))
+    }
+
+    #[test]
+    fn cancel_in_numerator() {
+        let den = pow(add(x(), num(1.0)), num(2.0));
+        let expr = div(sub(add(x(), num(1.0)), x()), den.clone());
+
+        assert_eq!(simplify(&expr), div(num(1.0), den));
+    }
+
+    #[test]
+    fn gather_coefficients() {
+        let expr = add(sub(mul(num(3.0), x()), num(2.0)), sub(x(), num(4.0)));
+
+        assert_eq!(simplify(&expr), sub(mul(num(4.0), x()), num(6.0)));
+        assert_eq!(simplify(&sub(x(), x())), num(0.0));
+    }
+}
@@ -21,6 +21,7 @@ pub struct Timer<'a> {
 }
 
 impl<'a> Timer<'a> {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(title: &'a str) -> Self {
         Timer {
             start: Instant::now(),
@@ -28,26 +29,18 @@ impl<'a> Timer<'a> {
         }
     }
 
-    pub fn top(&self) {
+    pub fn top(&self) -> String {
         let duration = self.start.elapsed();
         let micro = duration.as_micros();
         let milli = duration.as_millis();
-        println!(
-            "{}",
-            format!(
-                "{} - time elapsed: {}us ({}ms).",
-                to_color(format!("[b:{}]", self.title), milli).bold(),
-                micro,
-                milli
-            )
-            .dimmed()
-        );
-    }
-}
-
-impl<'a> Drop for Timer<'a> {
-    fn drop(&mut self) {
-        self.top()
+        format!(
+            "{} - time elapsed: {}us ({}ms).",
+            to_color(format!("[b:{}]", self.title), milli).bold(),
+            micro,
+            milli
+        )
+        .dimmed()
+        .to_string()
     }
 }
 
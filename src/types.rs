@@ -10,21 +10,23 @@
 /*                                                                            */
 /* ************************************************************************** */
 
+mod complex;
+mod fraction;
 mod imaginary;
 mod matrix;
+mod natural;
+mod polynomial;
 mod rational;
+mod surd;
 
+pub use complex::Complex;
+pub use fraction::Fraction;
 pub use imaginary::Imaginary;
 pub use matrix::{Matrix, MatrixError};
+pub use natural::Natural;
+pub use polynomial::Polynomial;
 pub use rational::Rational;
+pub use surd::Surd;
 
 use crate::computor::ComputorError;
 type OpResult<T> = Result<T, ComputorError>;
-
-fn read_overflow(res: (u64, bool)) -> OpResult<u64> {
-    if !res.1 {
-        Ok(res.0)
-    } else {
-        Err(ComputorError::overflow_abort())
-    }
-}
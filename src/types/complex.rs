@@ -0,0 +1,236 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   complex.rs                                         :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::Imaginary;
+
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn from_im(val: &Imaginary) -> Self {
+        Complex::new(val.get_real().get_val(), val.get_irreal().get_val())
+    }
+
+    pub fn to_im(self) -> Option<Imaginary> {
+        if self.re.is_finite() && self.im.is_finite() {
+            Some(Imaginary::new(self.re, self.im))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.im == 0.0
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+
+    pub fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+
+    pub fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    pub fn div(self, other: Complex) -> Complex {
+        let den = other.re * other.re + other.im * other.im;
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / den,
+            (self.im * other.re - self.re * other.im) / den,
+        )
+    }
+
+    pub fn scale(self, coef: f64) -> Complex {
+        Complex::new(self.re * coef, self.im * coef)
+    }
+
+    pub fn exp(self) -> Complex {
+        let modulus = self.re.exp();
+        Complex::new(modulus * self.im.cos(), modulus * self.im.sin())
+    }
+
+    pub fn ln(self) -> Complex {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+
+    pub fn powf(self, power: f64) -> Complex {
+        if self.re == 0.0 && self.im == 0.0 {
+            return self;
+        }
+        self.ln().scale(power).exp()
+    }
+
+    pub fn sqrt(self) -> Complex {
+        if self.is_real() {
+            return if self.re >= 0.0 {
+                Complex::new(self.re.sqrt(), 0.0)
+            } else {
+                Complex::new(0.0, (-self.re).sqrt())
+            };
+        }
+        let modulus = self.norm();
+        let re = ((modulus + self.re) / 2.0).sqrt();
+        let im = ((modulus - self.re) / 2.0).sqrt();
+        Complex::new(re, if self.im < 0.0 { -im } else { im })
+    }
+
+    /// Real cube root for real values, principal cube root otherwise.
+    pub fn cbrt(self) -> Complex {
+        if self.is_real() {
+            Complex::new(self.re.cbrt(), 0.0)
+        } else {
+            self.powf(1.0 / 3.0)
+        }
+    }
+
+    pub fn sin(self) -> Complex {
+        Complex::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    pub fn cos(self) -> Complex {
+        Complex::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+
+    pub fn tan(self) -> Complex {
+        self.sin().div(self.cos())
+    }
+
+    pub fn sinh(self) -> Complex {
+        Complex::new(
+            self.re.sinh() * self.im.cos(),
+            self.re.cosh() * self.im.sin(),
+        )
+    }
+
+    pub fn cosh(self) -> Complex {
+        Complex::new(
+            self.re.cosh() * self.im.cos(),
+            self.re.sinh() * self.im.sin(),
+        )
+    }
+
+    pub fn tanh(self) -> Complex {
+        self.sinh().div(self.cosh())
+    }
+
+    pub fn asin(self) -> Complex {
+        if self.is_real() && self.re.abs() <= 1.0 {
+            return Complex::new(self.re.asin(), 0.0);
+        }
+        let one = Complex::new(1.0, 0.0);
+        let i = Complex::new(0.0, 1.0);
+        let inner = i.mul(self).add(one.sub(self.mul(self)).sqrt());
+        inner.ln().mul(Complex::new(0.0, -1.0))
+    }
+
+    pub fn acos(self) -> Complex {
+        if self.is_real() && self.re.abs() <= 1.0 {
+            return Complex::new(self.re.acos(), 0.0);
+        }
+        Complex::new(PI / 2.0, 0.0).sub(self.asin())
+    }
+
+    pub fn atan(self) -> Complex {
+        if self.is_real() {
+            return Complex::new(self.re.atan(), 0.0);
+        }
+        let one = Complex::new(1.0, 0.0);
+        let iz = Complex::new(0.0, 1.0).mul(self);
+        let diff = one.sub(iz).ln().sub(one.add(iz).ln());
+        Complex::new(0.0, 0.5).mul(diff)
+    }
+
+    pub fn asinh(self) -> Complex {
+        if self.is_real() {
+            return Complex::new(self.re.asinh(), 0.0);
+        }
+        let one = Complex::new(1.0, 0.0);
+        self.add(self.mul(self).add(one).sqrt()).ln()
+    }
+
+    pub fn acosh(self) -> Complex {
+        if self.is_real() && self.re >= 1.0 {
+            return Complex::new(self.re.acosh(), 0.0);
+        }
+        let one = Complex::new(1.0, 0.0);
+        let root = self.add(one).sqrt().mul(self.sub(one).sqrt());
+        self.add(root).ln()
+    }
+
+    pub fn atanh(self) -> Complex {
+        if self.is_real() && self.re.abs() < 1.0 {
+            return Complex::new(self.re.atanh(), 0.0);
+        }
+        let one = Complex::new(1.0, 0.0);
+        one.add(self).ln().sub(one.sub(self).ln()).scale(0.5)
+    }
+}
+
+#[cfg(test)]
+mod function {
+    use super::Complex;
+
+    fn close(left: Complex, right: Complex) -> bool {
+        left.sub(right).norm() < 1e-10
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert!(close(
+            Complex::new(-3.0, -4.0).sqrt(),
+            Complex::new(1.0, -2.0)
+        ));
+        assert_eq!(Complex::new(-8.0, 0.0).cbrt(), Complex::new(-2.0, 0.0));
+    }
+
+    #[test]
+    fn inverse() {
+        let val = Complex::new(0.5, 0.7);
+        assert!(close(val.sin().asin(), val));
+        assert!(close(val.cos().acos(), val));
+        assert!(close(val.tan().atan(), val));
+        assert!(close(val.sinh().asinh(), val));
+        assert!(close(val.cosh().acosh(), val));
+        assert!(close(val.tanh().atanh(), val));
+        assert!(close(val.exp().ln(), val));
+    }
+}
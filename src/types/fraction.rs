@@ -0,0 +1,250 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   fraction.rs                                        :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::imaginary::Imaginary;
+use super::polynomial::Polynomial;
+use super::OpResult;
+use crate::computor::ComputorError;
+use std::{fmt, ops, slice};
+
+/// The denominator is monic. Factors divided out are kept in `poles`, the
+/// expression staying undefined where they are zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fraction {
+    num: Polynomial,
+    den: Polynomial,
+    poles: Vec<Polynomial>,
+}
+
+impl Fraction {
+    pub fn new(num: Polynomial, den: Polynomial) -> OpResult<Self> {
+        if den.is_zero() {
+            return Err(ComputorError::div_by_zero());
+        }
+        if let [(mono, _)] = den.terms()[..] {
+            let poles: Vec<Polynomial> = mono
+                .iter()
+                .filter(|(_, pow)| **pow > 0)
+                .map(|(id, _)| Polynomial::unknown(id))
+                .collect();
+            return Ok(Fraction::from_poly(num.div(&den)?).with_poles(&poles));
+        }
+        if let Ok((quotient, rem)) = num.divrem(&den) {
+            if rem.is_zero() {
+                return Ok(Fraction::from_poly(quotient).with_poles(&[den]));
+            }
+        }
+        let (mut num, mut den) = (num, den);
+        let mut poles = Vec::new();
+        if let Ok(common) = num.gcd(&den) {
+            if common.as_constant().is_none() {
+                num = num.divrem(&common)?.0;
+                den = den.divrem(&common)?.0;
+                poles.push(common);
+            }
+        }
+        let lead = match den.terms().first() {
+            Some((_, coef)) => Polynomial::constant((*coef).clone()),
+            None => return Err(ComputorError::div_by_zero()),
+        };
+        Ok(Fraction {
+            num: num.div(&lead)?,
+            den: den.div(&lead)?,
+            poles,
+        })
+    }
+
+    pub fn from_poly(poly: Polynomial) -> Self {
+        Fraction {
+            num: poly,
+            den: Polynomial::constant(Imaginary::new(1.0, 0.0)),
+            poles: Vec::new(),
+        }
+    }
+
+    fn with_poles(mut self, poles: &[Polynomial]) -> Self {
+        for pole in poles {
+            if pole.as_constant().is_none() && !self.poles.contains(pole) {
+                self.poles.push(pole.clone());
+            }
+        }
+        self
+    }
+
+    pub fn num(&self) -> &Polynomial {
+        &self.num
+    }
+
+    pub fn den(&self) -> &Polynomial {
+        &self.den
+    }
+
+    pub fn poles(&self) -> &[Polynomial] {
+        &self.poles
+    }
+
+    pub fn as_poly(&self) -> Option<Polynomial> {
+        let den = self.den.as_constant()?;
+        self.num.div(&Polynomial::constant(den)).ok()
+    }
+
+    pub fn add(&self, other: &Fraction) -> OpResult<Self> {
+        let res = if self.den == other.den {
+            Fraction::new(self.num.add(&other.num)?, self.den.clone())?
+        } else {
+            Fraction::new(
+                self.num.mul(&other.den)?.add(&other.num.mul(&self.den)?)?,
+                self.den.mul(&other.den)?,
+            )?
+        };
+        Ok(res.with_poles(&self.poles).with_poles(&other.poles))
+    }
+
+    pub fn sub(&self, other: &Fraction) -> OpResult<Self> {
+        self.add(&-other.clone())
+    }
+
+    pub fn mul(&self, other: &Fraction) -> OpResult<Self> {
+        let res = Fraction::new(
+            self.num.mul(&other.num)?,
+            self.den.mul(&other.den)?,
+        )?;
+        Ok(res.with_poles(&self.poles).with_poles(&other.poles))
+    }
+
+    pub fn div(&self, other: &Fraction) -> OpResult<Self> {
+        let res = Fraction::new(
+            self.num.mul(&other.den)?,
+            self.den.mul(&other.num)?,
+        )?;
+        let res = res.with_poles(&self.poles).with_poles(&other.poles);
+        Ok(res.with_poles(slice::from_ref(&other.den)))
+    }
+
+    pub fn pow(&self, power: i32) -> OpResult<Self> {
+        if power < 0 {
+            let inverse = Fraction::new(self.den.clone(), self.num.clone())?
+                .with_poles(&self.poles)
+                .with_poles(slice::from_ref(&self.den));
+            return match power.checked_neg() {
+                Some(power) => inverse.pow(power),
+                None => Err(ComputorError::overflow_abort()),
+            };
+        }
+        let res = Fraction::new(self.num.pow(power)?, self.den.pow(power)?)?;
+        Ok(res.with_poles(&self.poles))
+    }
+}
+
+fn operand(poly: &Polynomial) -> String {
+    match poly.terms().len() {
+        0 | 1 => poly.to_string(),
+        _ => format!("({})", poly),
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.as_poly() {
+            Some(poly) => write!(f, "{}", poly),
+            None => {
+                write!(f, "{} / {}", operand(&self.num), operand(&self.den))
+            }
+        }
+    }
+}
+
+impl ops::Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        Fraction {
+            num: -self.num,
+            den: self.den,
+            poles: self.poles,
+        }
+    }
+}
+
+#[cfg(test)]
+mod operator {
+    use super::Fraction;
+    use crate::types::{Imaginary, Polynomial};
+
+    fn val(real: f64) -> Polynomial {
+        Polynomial::constant(Imaginary::new(real, 0.0))
+    }
+
+    fn binomial(root: f64) -> Polynomial {
+        Polynomial::unknown("x").sub(&val(root)).unwrap()
+    }
+
+    #[test]
+    fn exact_quotient() {
+        let num = binomial(1.0).mul(&binomial(-1.0)).unwrap();
+        let res = Fraction::new(num, binomial(1.0)).unwrap();
+
+        assert_eq!(res.as_poly(), Some(binomial(-1.0)));
+    }
+
+    #[test]
+    fn divided_factors_kept() {
+        let num = binomial(1.0).mul(&binomial(-1.0)).unwrap();
+        let res = Fraction::new(num, binomial(1.0)).unwrap();
+        assert_eq!(res.poles(), &[binomial(1.0)]);
+
+        let num = binomial(2.0).mul(&binomial(3.0)).unwrap();
+        let den = binomial(2.0).mul(&binomial(1.0)).unwrap();
+        let res = Fraction::new(num, den).unwrap();
+        assert_eq!(res.poles(), &[binomial(2.0)]);
+
+        let x = Polynomial::unknown("x");
+        let res = Fraction::new(x.mul(&x).unwrap(), x.clone()).unwrap();
+        assert_eq!(res.as_poly(), Some(x.clone()));
+        assert_eq!(res.poles(), std::slice::from_ref(&x));
+
+        let inv = Fraction::new(val(1.0), x.clone()).unwrap();
+        let res = Fraction::from_poly(val(1.0)).div(&inv).unwrap();
+        assert_eq!(res.as_poly(), Some(x.clone()));
+        assert_eq!(res.poles(), &[x]);
+    }
+
+    #[test]
+    fn cancel_common_factor() {
+        let num = binomial(2.0).mul(&binomial(3.0)).unwrap();
+        let den = binomial(2.0).mul(&binomial(1.0)).unwrap();
+        let res = Fraction::new(num, den).unwrap();
+
+        assert_eq!(res.to_string(), "(x - 3) / (x - 1)");
+        assert!(res.as_poly().is_none());
+    }
+
+    #[test]
+    fn add_fractions() {
+        let one = Fraction::new(val(1.0), binomial(1.0)).unwrap();
+        let two = Fraction::new(val(1.0), binomial(-1.0)).unwrap();
+        let sum = one.add(&two).unwrap();
+
+        assert_eq!(sum.to_string(), "2 * x / (x^2 - 1)");
+        assert_eq!(sum.sub(&two).unwrap().to_string(), "1 / (x - 1)");
+        let prod = one.mul(&Fraction::from_poly(binomial(1.0))).unwrap();
+        assert_eq!(prod.to_string(), "1");
+    }
+
+    #[test]
+    fn negative_power() {
+        let frac = Fraction::from_poly(binomial(1.0)).pow(-2).unwrap();
+        assert!(Fraction::from_poly(binomial(1.0)).pow(i32::MIN).is_err());
+
+        assert_eq!(frac.to_string(), "1 / (x^2 - 2 * x + 1)");
+    }
+}
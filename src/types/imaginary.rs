@@ -15,7 +15,7 @@ use super::OpResult;
 use crate::computor::ComputorError;
 use std::{cmp, fmt, ops};
 
-#[derive(Eq, Copy, Clone, Debug)]
+#[derive(Eq, Clone, Debug)]
 pub struct Imaginary {
     real: Rational,
     irreal: Rational,
@@ -30,29 +30,29 @@ impl Imaginary {
     }
 
     pub fn parse(raw: &str) -> Option<Self> {
-        let len = raw.len();
-        if len > 13 {
-            return None;
-        }
-        if raw.ends_with('i') {
-            if len > 1 {
-                match raw[..len - 1].parse::<f64>() {
-                    Ok(val) => Some(Imaginary::new(0.0, val)),
-                    Err(_err) => None,
-                }
-            } else {
-                Some(Imaginary::new(0.0, 1.0))
-            }
-        } else {
-            match raw.parse::<f64>() {
-                Ok(val) => Some(Imaginary::new(val, 0.0)),
-                Err(_err) => None,
-            }
+        match raw.strip_suffix('i') {
+            Some("") => Some(Imaginary::new(0.0, 1.0)),
+            Some(irreal) => Some(Imaginary {
+                real: Rational::zero(),
+                irreal: Rational::parse(irreal)?,
+            }),
+            None => Some(Imaginary {
+                real: Rational::parse(raw)?,
+                irreal: Rational::zero(),
+            }),
         }
     }
 
     pub fn get_real(&self) -> Rational {
-        self.real
+        self.real.clone()
+    }
+
+    pub fn get_irreal(&self) -> Rational {
+        self.irreal.clone()
+    }
+
+    pub fn from_parts(real: Rational, irreal: Rational) -> Self {
+        Imaginary { real, irreal }
     }
 
     pub fn is_real(&self) -> bool {
@@ -73,7 +73,7 @@ impl Imaginary {
     }
 
     pub fn sub(&self, other: &Imaginary) -> OpResult<Self> {
-        let negated = -*other;
+        let negated = -other.clone();
         self.add(&negated)
     }
 
@@ -123,17 +123,33 @@ impl Imaginary {
         }
     }
 
+    #[allow(clippy::needless_return)]
     pub fn pow(&self, power: i32) -> OpResult<Self> {
         if power < 0 {
             return (Imaginary::new(1.0, 0.0).div(self)?).pow(-power);
         }
+        if self.is_real() {
+            return Ok(Imaginary {
+                real: self.real.pow(power)?,
+                irreal: Rational::zero(),
+            });
+        }
+        let bits = cmp::max(self.real.bits(), self.irreal.bits()) + 1;
+        if bits.saturating_mul(power as u64) > Rational::max_bits() {
+            return Err(ComputorError::overflow_abort());
+        }
         let mut res = Imaginary::new(1.0, 0.0);
-        let pow: u32 = power as u32;
-        let mut i: u32 = 0;
+        let mut base = self.clone();
+        let mut pow: u32 = power as u32;
 
-        while pow > i {
-            i += 1;
-            res = res.mul(self)?;
+        while pow > 0 {
+            if pow % 2 == 1 {
+                res = res.mul(&base)?;
+            }
+            pow /= 2;
+            if pow > 0 {
+                base = base.mul(&base)?;
+            }
         }
         res.real.simplify();
         res.irreal.simplify();
@@ -176,9 +192,9 @@ impl fmt::Display for Imaginary {
             write!(
                 f,
                 "{}",
-                match self.irreal {
-                    zero if zero == Rational::zero() => String::from("0"),
-                    one if one == Rational::new(1.0) => String::from("i"),
+                match &self.irreal {
+                    zero if *zero == Rational::zero() => String::from("0"),
+                    one if *one == Rational::new(1.0) => String::from("i"),
                     other => format!("{}i", other),
                 }
             )
@@ -10,10 +10,12 @@
 /*                                                                            */
 /* ************************************************************************** */
 
-use super::{Imaginary, OpResult};
+use super::{Imaginary, OpResult, Rational};
 use crate::computor::ComputorError;
 
+use std::cmp;
 use std::fmt;
+use std::ops::Range;
 
 #[derive(Debug)]
 pub enum MatrixError {
@@ -56,6 +58,18 @@ impl Matrix {
         }
     }
 
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn data(&self) -> &Vec<Imaginary> {
+        &self.data
+    }
+
     pub fn push(&mut self, val: Imaginary) {
         self.data.push(val);
     }
@@ -72,6 +86,39 @@ impl Matrix {
         })
     }
 
+    pub fn map<F>(&self, mut op: F) -> OpResult<Self>
+    where
+        F: FnMut(&Imaginary) -> OpResult<Imaginary>,
+    {
+        let mut data: Vec<Imaginary> = Vec::new();
+        for val in self.data.iter() {
+            data.push(op(val)?);
+        }
+        Ok(Matrix {
+            width: self.width,
+            height: self.height,
+            data,
+        })
+    }
+
+    pub fn zip_with<F>(&self, other: &Matrix, mut op: F) -> OpResult<Self>
+    where
+        F: FnMut(&Imaginary, &Imaginary) -> OpResult<Imaginary>,
+    {
+        if self.width != other.width || self.height != other.height {
+            return Err(ComputorError::matrix_dim(false));
+        }
+        let mut data: Vec<Imaginary> = Vec::new();
+        for (val_a, val_b) in self.data.iter().zip(other.data.iter()) {
+            data.push(op(val_a, val_b)?);
+        }
+        Ok(Matrix {
+            width: self.width,
+            height: self.height,
+            data,
+        })
+    }
+
     pub fn add(&self, other: &Matrix) -> OpResult<Self> {
         let mut data: Vec<Imaginary> = Vec::new();
         if self.width != other.width || self.height != other.height {
@@ -125,6 +172,240 @@ impl Matrix {
             data,
         })
     }
+
+    pub fn identity(size: u32) -> Self {
+        let mut data: Vec<Imaginary> = Vec::new();
+        for i in 0..size * size {
+            let one = i / size == i % size;
+            data.push(Imaginary::new(if one { 1.0 } else { 0.0 }, 0.0));
+        }
+        Matrix {
+            width: size,
+            height: size,
+            data,
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data: Vec<Imaginary> = Vec::new();
+        for i in 0..self.width * self.height {
+            let (row, col) = (i / self.height, i % self.height);
+            data.push(self.data[(col * self.width + row) as usize].clone());
+        }
+        Matrix {
+            width: self.height,
+            height: self.width,
+            data,
+        }
+    }
+
+    pub fn trace(&self) -> OpResult<Imaginary> {
+        self.square("trace")?;
+        let mut res = Imaginary::new(0.0, 0.0);
+        for i in 0..self.width {
+            res = res.add(&self.data[(i * self.width + i) as usize])?;
+        }
+        Ok(res)
+    }
+
+    pub fn det(&self) -> OpResult<Imaginary> {
+        self.square("determinant")?;
+        let echelon = Echelon::reduce(self.rows(), 0)?;
+        match echelon.rank < self.height as usize {
+            true => Ok(Imaginary::new(0.0, 0.0)),
+            false => Ok(echelon.det),
+        }
+    }
+
+    pub fn rank(&self) -> OpResult<usize> {
+        Ok(Echelon::reduce(self.rows(), 0)?.rank)
+    }
+
+    pub fn inverse(&self) -> OpResult<Self> {
+        self.square("inverse")?;
+        self.solve(&Matrix::identity(self.width))
+    }
+
+    pub fn solve(&self, rhs: &Matrix) -> OpResult<Self> {
+        self.square("solution")?;
+        if rhs.height != self.height {
+            return Err(ComputorError::matrix_solve());
+        }
+        let size = self.width as usize;
+        let mut rows = self.rows();
+        for (pos, row) in rhs.rows().into_iter().enumerate() {
+            rows[pos].extend(row);
+        }
+        let echelon = Echelon::reduce(rows, rhs.width as usize)?;
+        if echelon.rank < size {
+            return Err(ComputorError::singular());
+        }
+        let mut data: Vec<Imaginary> = Vec::new();
+        for row in echelon.rows.into_iter() {
+            data.extend(row.into_iter().skip(size));
+        }
+        Ok(Matrix {
+            width: rhs.width,
+            height: rhs.height,
+            data,
+        })
+    }
+
+    pub fn div(&self, other: &Matrix) -> OpResult<Self> {
+        other.square("quotient")?;
+        if self.width != other.height {
+            return Err(ComputorError::matrix_solve());
+        }
+        Ok(other.transpose().solve(&self.transpose())?.transpose())
+    }
+
+    pub fn div_scalar(&self, val: &Imaginary) -> OpResult<Self> {
+        if *val == Imaginary::new(0.0, 0.0) {
+            return Err(ComputorError::div_by_zero());
+        }
+        self.apply_mul(Imaginary::new(1.0, 0.0).div(val)?)
+    }
+
+    pub fn pow(&self, power: i32) -> OpResult<Self> {
+        self.square("power")?;
+        let mut base = match power < 0 {
+            true => self.inverse()?,
+            false => self.clone(),
+        };
+        let mut power = power.unsigned_abs();
+        let bits = base
+            .data
+            .iter()
+            .map(|val| cmp::max(val.get_real().bits(), val.get_irreal().bits()))
+            .max()
+            .unwrap_or(0)
+            + u64::from(32 - self.width.leading_zeros())
+            + 1;
+        if power > 1 && bits.saturating_mul(power as u64) > Rational::max_bits()
+        {
+            return Err(ComputorError::overflow_abort());
+        }
+        let mut res = Matrix::identity(self.width);
+        while power > 0 {
+            if power % 2 == 1 {
+                res = res.mul(&base)?;
+            }
+            power /= 2;
+            if power > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Cells at the given 1-based row and column, `None` taking them all.
+    pub fn slice(&self, row: Option<u32>, col: Option<u32>) -> OpResult<Self> {
+        let (rows, cols) = (self.span(row, true)?, self.span(col, false)?);
+        let mut data: Vec<Imaginary> = Vec::new();
+        for i in rows.clone() {
+            for j in cols.clone() {
+                data.push(self.data[i * self.width as usize + j].clone());
+            }
+        }
+        Ok(Matrix {
+            width: cols.len() as u32,
+            height: rows.len() as u32,
+            data,
+        })
+    }
+
+    pub fn assign(
+        &self,
+        row: Option<u32>,
+        col: Option<u32>,
+        val: &Matrix,
+    ) -> OpResult<Self> {
+        let (rows, cols) = (self.span(row, true)?, self.span(col, false)?);
+        if val.height as usize != rows.len() || val.width as usize != cols.len()
+        {
+            return Err(ComputorError::matrix_slice());
+        }
+        let mut res = self.clone();
+        let mut cells = val.data.iter();
+        for i in rows {
+            for j in cols.clone() {
+                if let Some(cell) = cells.next() {
+                    res.data[i * self.width as usize + j] = cell.clone();
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    fn span(&self, index: Option<u32>, row: bool) -> OpResult<Range<usize>> {
+        let bound = if row { self.height } else { self.width };
+        match index {
+            None => Ok(0..bound as usize),
+            Some(index) if index >= 1 && index <= bound => {
+                Ok(index as usize - 1..index as usize)
+            }
+            Some(index) => {
+                Err(ComputorError::matrix_index(&index.to_string(), bound, row))
+            }
+        }
+    }
+
+    fn square(&self, op: &str) -> OpResult<()> {
+        match self.width == self.height {
+            true => Ok(()),
+            false => Err(ComputorError::matrix_square(op)),
+        }
+    }
+
+    fn rows(&self) -> Vec<Vec<Imaginary>> {
+        self.data
+            .chunks(self.width as usize)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+}
+
+struct Echelon {
+    rows: Vec<Vec<Imaginary>>,
+    rank: usize,
+    det: Imaginary,
+}
+
+impl Echelon {
+    fn reduce(mut rows: Vec<Vec<Imaginary>>, extra: usize) -> OpResult<Self> {
+        let zero = Imaginary::new(0.0, 0.0);
+        let width = rows.first().map_or(0, |row| row.len()) - extra;
+        let mut det = Imaginary::new(1.0, 0.0);
+        let mut rank = 0;
+        for col in 0..width {
+            let pivot = match (rank..rows.len()).find(|&i| rows[i][col] != zero)
+            {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            if pivot != rank {
+                rows.swap(pivot, rank);
+                det = -det;
+            }
+            let lead = rows[rank][col].clone();
+            det = det.mul(&lead)?;
+            for val in rows[rank].iter_mut() {
+                *val = val.div(&lead)?;
+            }
+            for i in 0..rows.len() {
+                let coef = rows[i][col].clone();
+                if i == rank || coef == zero {
+                    continue;
+                }
+                for j in 0..rows[i].len() {
+                    let scaled = coef.mul(&rows[rank][j])?;
+                    rows[i][j] = rows[i][j].sub(&scaled)?;
+                }
+            }
+            rank += 1;
+        }
+        Ok(Echelon { rows, rank, det })
+    }
 }
 
 impl fmt::Display for Matrix {
@@ -152,3 +433,139 @@ impl fmt::Display for Matrix {
         write!(f, "{}", print)
     }
 }
+
+#[cfg(test)]
+mod algebra {
+    use super::Matrix;
+    use crate::computor::ErrorKind;
+    use crate::types::Imaginary;
+
+    fn matrix(width: u32, vals: &[f64]) -> Matrix {
+        let mut res = Matrix::new(width, vals.len() as u32 / width);
+        for val in vals.iter() {
+            res.push(Imaginary::new(*val, 0.0));
+        }
+        res
+    }
+
+    #[test]
+    fn determinant() {
+        let mat = matrix(3, &[2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
+
+        assert_eq!(mat.det().unwrap(), Imaginary::new(6.0, 0.0));
+        assert_eq!(
+            matrix(2, &[0.0, 1.0, 1.0, 0.0]).det().unwrap(),
+            Imaginary::new(-1.0, 0.0)
+        );
+        assert_eq!(
+            matrix(2, &[1.0, 2.0, 2.0, 4.0]).det().unwrap(),
+            Imaginary::new(0.0, 0.0)
+        );
+        assert!(matrix(3, &[1.0; 6]).det().is_err());
+    }
+
+    #[test]
+    fn inverse() {
+        let mat = matrix(2, &[1.0, 2.0, 3.0, 4.0]);
+        let inv = mat.inverse().unwrap();
+
+        assert_eq!(inv, matrix(2, &[-2.0, 1.0, 1.5, -0.5]));
+        assert_eq!(mat.mul(&inv).unwrap(), Matrix::identity(2));
+        let singular = matrix(2, &[1.0, 2.0, 2.0, 4.0]).inverse();
+        assert!(matches!(singular.unwrap_err().kind(), ErrorKind::Singular));
+    }
+
+    #[test]
+    fn transpose_trace_rank() {
+        let mat = matrix(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_eq!(mat.transpose(), matrix(2, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]));
+        assert_eq!(mat.rank().unwrap(), 2);
+        assert_eq!(matrix(2, &[1.0, 2.0, 2.0, 4.0]).rank().unwrap(), 1);
+        assert_eq!(
+            matrix(2, &[1.0, 2.0, 3.0, 4.0]).trace().unwrap(),
+            Imaginary::new(5.0, 0.0)
+        );
+        assert!(mat.trace().is_err());
+    }
+
+    #[test]
+    fn power() {
+        let mat = matrix(2, &[1.0, 1.0, 1.0, 0.0]);
+
+        assert_eq!(mat.pow(0).unwrap(), Matrix::identity(2));
+        assert_eq!(mat.pow(1).unwrap(), mat);
+        assert_eq!(mat.pow(10).unwrap(), matrix(2, &[89.0, 55.0, 55.0, 34.0]));
+        assert_eq!(mat.pow(-2).unwrap(), matrix(2, &[1.0, -1.0, -1.0, 2.0]));
+        assert_eq!(
+            mat.pow(-3).unwrap().mul(&mat.pow(3).unwrap()).unwrap(),
+            Matrix::identity(2)
+        );
+        let singular = matrix(2, &[1.0, 2.0, 2.0, 4.0]).pow(-1);
+        assert!(matches!(singular.unwrap_err().kind(), ErrorKind::Singular));
+        let rect = matrix(3, &[1.0; 6]).pow(2);
+        assert!(matches!(rect.unwrap_err().kind(), ErrorKind::MatrixDim));
+        let huge = matrix(1, &[2.0]).pow(100_000_000);
+        assert!(matches!(huge.unwrap_err().kind(), ErrorKind::OverflowAbort));
+        let unit = matrix(2, &[1.0, 1.0, 0.0, 1.0]);
+        assert!(unit.pow(i32::MIN).is_err());
+    }
+
+    #[test]
+    fn division() {
+        let mat = matrix(2, &[2.0, 1.0, 1.0, 3.0]);
+        let rhs = matrix(1, &[3.0, 5.0]);
+
+        assert_eq!(mat.solve(&rhs).unwrap(), matrix(1, &[0.8, 1.4]));
+        assert_eq!(
+            mat.div_scalar(&Imaginary::new(2.0, 0.0)).unwrap(),
+            matrix(2, &[1.0, 0.5, 0.5, 1.5])
+        );
+        let left = matrix(2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let quot = left.div(&mat).unwrap();
+        assert_eq!(quot.mul(&mat).unwrap(), left);
+        assert_eq!(quot, left.mul(&mat.inverse().unwrap()).unwrap());
+        let singular = matrix(2, &[1.0, 2.0, 2.0, 4.0]).solve(&rhs);
+        assert!(matches!(singular.unwrap_err().kind(), ErrorKind::Singular));
+        let wrong = mat.solve(&matrix(1, &[1.0, 2.0, 3.0]));
+        assert!(matches!(wrong.unwrap_err().kind(), ErrorKind::MatrixDim));
+        assert!(mat.div_scalar(&Imaginary::new(0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn cellwise() {
+        let mat_a = matrix(2, &[1.0, 2.0, 3.0, 4.0]);
+        let mat_b = matrix(2, &[2.0, 2.0, 3.0, 8.0]);
+
+        let prod = mat_a.zip_with(&mat_b, |a, b| a.mul(b)).unwrap();
+        assert_eq!(prod, matrix(2, &[2.0, 4.0, 9.0, 32.0]));
+        let two = Imaginary::new(2.0, 0.0);
+        let sum = mat_a.map(|val| val.add(&two)).unwrap();
+        assert_eq!(sum, matrix(2, &[3.0, 4.0, 5.0, 6.0]));
+        let wrong = mat_a.zip_with(&matrix(1, &[1.0, 2.0]), |a, b| a.mul(b));
+        assert!(matches!(wrong.unwrap_err().kind(), ErrorKind::MatrixDim));
+    }
+
+    #[test]
+    fn slicing() {
+        let mat = matrix(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_eq!(mat.slice(Some(2), Some(3)).unwrap(), matrix(1, &[6.0]));
+        assert_eq!(
+            mat.slice(Some(1), None).unwrap(),
+            matrix(3, &[1.0, 2.0, 3.0])
+        );
+        assert_eq!(mat.slice(None, Some(2)).unwrap(), matrix(1, &[2.0, 5.0]));
+        assert_eq!(mat.slice(None, None).unwrap(), mat);
+        let out = mat.slice(Some(3), Some(1));
+        assert!(matches!(out.unwrap_err().kind(), ErrorKind::MatrixIndex));
+        assert!(mat.slice(Some(1), Some(0)).is_err());
+
+        let cell = mat.assign(Some(1), Some(2), &matrix(1, &[9.0])).unwrap();
+        assert_eq!(cell, matrix(3, &[1.0, 9.0, 3.0, 4.0, 5.0, 6.0]));
+        let col = mat.assign(None, Some(3), &matrix(1, &[0.0, 0.0])).unwrap();
+        assert_eq!(col, matrix(3, &[1.0, 2.0, 0.0, 4.0, 5.0, 0.0]));
+        let wrong = mat.assign(Some(1), None, &matrix(2, &[0.0, 0.0]));
+        assert!(matches!(wrong.unwrap_err().kind(), ErrorKind::MatrixDim));
+    }
+}
@@ -0,0 +1,476 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   natural.rs                                         :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use std::{cmp, fmt};
+
+const LIMB_BITS: u32 = 32;
+const DEC_CHUNK: u32 = 1_000_000_000;
+const DEC_CHUNK_LEN: usize = 9;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Natural {
+    limbs: Vec<u32>,
+}
+
+impl Natural {
+    pub fn zero() -> Self {
+        Natural { limbs: Vec::new() }
+    }
+
+    pub fn from_u64(val: u64) -> Self {
+        let mut nat = Natural {
+            limbs: vec![val as u32, (val >> LIMB_BITS) as u32],
+        };
+        nat.normalize();
+        nat
+    }
+
+    pub fn from_f64(val: f64) -> Self {
+        let val = val.abs().trunc();
+        if !val.is_finite() || val < 1.0 {
+            return Natural::zero();
+        }
+        if val < u64::MAX as f64 {
+            return Natural::from_u64(val as u64);
+        }
+        let bits = val.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as u32 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        Natural::from_u64(mantissa).shl(exp)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.limbs.len() == 1 && self.limbs[0] == 1
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|low| low % 2 == 0)
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => {
+                self.limbs.len() as u64 * LIMB_BITS as u64
+                    - top.leading_zeros() as u64
+            }
+            None => 0,
+        }
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u64),
+            2 => Some(self.limbs[0] as u64 | (self.limbs[1] as u64) << 32),
+            _ => None,
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        match self.to_u64() {
+            Some(val) => val as f64,
+            None => {
+                let shift = self.bits() - 64;
+                let top = self.shr(shift as u32).to_u64().unwrap_or(u64::MAX);
+                top as f64 * 2.0_f64.powf(shift as f64)
+            }
+        }
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn add(&self, other: &Natural) -> Natural {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (&self.limbs, &other.limbs)
+        } else {
+            (&other.limbs, &self.limbs)
+        };
+        let mut limbs = Vec::with_capacity(long.len() + 1);
+        let mut carry: u64 = 0;
+        for (i, limb) in long.iter().enumerate() {
+            let sum = *limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push(sum as u32);
+            carry = sum >> LIMB_BITS;
+        }
+        limbs.push(carry as u32);
+        let mut res = Natural { limbs };
+        res.normalize();
+        res
+    }
+
+    /// Difference of two naturals, `other` must not be greater than `self`.
+    pub fn sub(&self, other: &Natural) -> Natural {
+        debug_assert!(*self >= *other, "Natural substraction underflow");
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow: i64 = 0;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let mut diff = *limb as i64
+                - *other.limbs.get(i).unwrap_or(&0) as i64
+                - borrow;
+            borrow = 0;
+            if diff < 0 {
+                diff += 1 << LIMB_BITS;
+                borrow = 1;
+            }
+            limbs.push(diff as u32);
+        }
+        let mut res = Natural { limbs };
+        res.normalize();
+        res
+    }
+
+    pub fn mul(&self, other: &Natural) -> Natural {
+        if self.is_zero() || other.is_zero() {
+            return Natural::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, left) in self.limbs.iter().enumerate() {
+            let mut carry: u64 = 0;
+            for (j, right) in other.limbs.iter().enumerate() {
+                let prod =
+                    *left as u64 * *right as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = prod as u32;
+                carry = prod >> LIMB_BITS;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        let mut res = Natural { limbs };
+        res.normalize();
+        res
+    }
+
+    fn mul_small(&self, val: u32) -> Natural {
+        self.mul(&Natural::from_u64(val as u64))
+    }
+
+    fn divrem_small(&self, div: u32) -> (Natural, u32) {
+        let mut limbs = vec![0u32; self.limbs.len()];
+        let mut rem: u64 = 0;
+        for i in (0..self.limbs.len()).rev() {
+            let cur = rem << LIMB_BITS | self.limbs[i] as u64;
+            limbs[i] = (cur / div as u64) as u32;
+            rem = cur % div as u64;
+        }
+        let mut quo = Natural { limbs };
+        quo.normalize();
+        (quo, rem as u32)
+    }
+
+    /// Euclidean division, `div` must not be zero.
+    pub fn divrem(&self, div: &Natural) -> (Natural, Natural) {
+        assert!(!div.is_zero(), "Natural division by zero");
+        if self < div {
+            return (Natural::zero(), self.clone());
+        }
+        if div.limbs.len() == 1 {
+            let (quo, rem) = self.divrem_small(div.limbs[0]);
+            return (quo, Natural::from_u64(rem as u64));
+        }
+        self.divrem_knuth(div)
+    }
+
+    fn divrem_knuth(&self, div: &Natural) -> (Natural, Natural) {
+        let n = div.limbs.len();
+        let m = self.limbs.len() - n;
+        let shift = div.limbs[n - 1].leading_zeros();
+        let vn = div.shl(shift).limbs;
+        let mut un = self.shl(shift).limbs;
+        un.resize(self.limbs.len() + 1, 0);
+        let mut quo = vec![0u32; m + 1];
+        let base: u64 = 1 << LIMB_BITS;
+
+        for j in (0..=m).rev() {
+            let num = (un[j + n] as u64) << LIMB_BITS | un[j + n - 1] as u64;
+            let mut qhat = num / vn[n - 1] as u64;
+            let mut rhat = num % vn[n - 1] as u64;
+            while qhat >= base
+                || qhat * vn[n - 2] as u64
+                    > (rhat << LIMB_BITS | un[j + n - 2] as u64)
+            {
+                qhat -= 1;
+                rhat += vn[n - 1] as u64;
+                if rhat >= base {
+                    break;
+                }
+            }
+            let mut borrow: i64 = 0;
+            for i in 0..n {
+                let prod = qhat * vn[i] as u64;
+                let diff =
+                    un[i + j] as i64 - borrow - (prod & (base - 1)) as i64;
+                un[i + j] = diff as u32;
+                borrow = (prod >> LIMB_BITS) as i64 - (diff >> LIMB_BITS);
+            }
+            let diff = un[j + n] as i64 - borrow;
+            un[j + n] = diff as u32;
+            if diff < 0 {
+                qhat -= 1;
+                let mut carry: u64 = 0;
+                for i in 0..n {
+                    let sum = un[i + j] as u64 + vn[i] as u64 + carry;
+                    un[i + j] = sum as u32;
+                    carry = sum >> LIMB_BITS;
+                }
+                un[j + n] = un[j + n].wrapping_add(carry as u32);
+            }
+            quo[j] = qhat as u32;
+        }
+        un.truncate(n);
+        let mut quo = Natural { limbs: quo };
+        quo.normalize();
+        let mut rem = Natural { limbs: un };
+        rem.normalize();
+        (quo, rem.shr(shift))
+    }
+
+    pub fn gcd(&self, other: &Natural) -> Natural {
+        let mut val_a = self.clone();
+        let mut val_b = other.clone();
+        while !val_b.is_zero() {
+            let (_, rem) = val_a.divrem(&val_b);
+            val_a = val_b;
+            val_b = rem;
+        }
+        val_a
+    }
+
+    pub fn pow(&self, mut power: u32) -> Natural {
+        let mut res = Natural::from_u64(1);
+        let mut base = self.clone();
+        while power > 0 {
+            if power % 2 == 1 {
+                res = res.mul(&base);
+            }
+            power /= 2;
+            if power > 0 {
+                base = base.mul(&base);
+            }
+        }
+        res
+    }
+
+    pub fn nth_root(&self, n: u32) -> Natural {
+        if self.is_zero() || n == 1 {
+            return self.clone();
+        }
+        let one = Natural::from_u64(1);
+        let prev_pow = n - 1;
+        let mut root = one.shl((self.bits() / n as u64) as u32 + 1);
+        loop {
+            let (quo, _) = self.divrem(&root.pow(prev_pow));
+            let next = root.mul_small(prev_pow).add(&quo).divrem_small(n).0;
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+
+    pub fn exact_root(&self, n: u32) -> Option<Natural> {
+        let root = self.nth_root(n);
+        if root.pow(n) == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    pub fn shl(&self, bits: u32) -> Natural {
+        if self.is_zero() {
+            return Natural::zero();
+        }
+        let whole = (bits / LIMB_BITS) as usize;
+        let part = bits % LIMB_BITS;
+        let mut limbs = vec![0u32; whole];
+        let mut carry: u32 = 0;
+        for limb in self.limbs.iter() {
+            if part == 0 {
+                limbs.push(*limb);
+            } else {
+                limbs.push(limb << part | carry);
+                carry = limb >> (LIMB_BITS - part);
+            }
+        }
+        limbs.push(carry);
+        let mut res = Natural { limbs };
+        res.normalize();
+        res
+    }
+
+    pub fn shr(&self, bits: u32) -> Natural {
+        let whole = (bits / LIMB_BITS) as usize;
+        let part = bits % LIMB_BITS;
+        if whole >= self.limbs.len() {
+            return Natural::zero();
+        }
+        let src = &self.limbs[whole..];
+        let mut limbs = Vec::with_capacity(src.len());
+        for (i, limb) in src.iter().enumerate() {
+            if part == 0 {
+                limbs.push(*limb);
+            } else {
+                let high = src.get(i + 1).unwrap_or(&0);
+                limbs.push(limb >> part | high << (LIMB_BITS - part));
+            }
+        }
+        let mut res = Natural { limbs };
+        res.normalize();
+        res
+    }
+
+    pub fn parse(digits: &str) -> Option<Natural> {
+        if digits.is_empty() || !digits.bytes().all(|ch| ch.is_ascii_digit()) {
+            return None;
+        }
+        let mut res = Natural::zero();
+        for chunk in digits.as_bytes().chunks(DEC_CHUNK_LEN) {
+            let val: u32 = std::str::from_utf8(chunk).ok()?.parse().ok()?;
+            res = res
+                .mul_small(10u32.pow(chunk.len() as u32))
+                .add(&Natural::from_u64(val as u64));
+        }
+        Some(res)
+    }
+}
+
+impl cmp::Ord for Natural {
+    fn cmp(&self, rhs: &Self) -> cmp::Ordering {
+        match self.limbs.len().cmp(&rhs.limbs.len()) {
+            cmp::Ordering::Equal => {
+                self.limbs.iter().rev().cmp(rhs.limbs.iter().rev())
+            }
+            other => other,
+        }
+    }
+}
+
+impl cmp::PartialOrd for Natural {
+    fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl fmt::Display for Natural {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut chunks: Vec<u32> = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quo, rem) = rest.divrem_small(DEC_CHUNK);
+            chunks.push(rem);
+            rest = quo;
+        }
+        let mut iter = chunks.iter().rev();
+        let mut res = format!("{}", iter.next().unwrap_or(&0));
+        for chunk in iter {
+            res += &format!("{:0width$}", chunk, width = DEC_CHUNK_LEN);
+        }
+        f.pad(&res)
+    }
+}
+
+#[cfg(test)]
+mod arithmetic {
+    use super::Natural;
+
+    fn nat(raw: &str) -> Natural {
+        Natural::parse(raw).unwrap()
+    }
+
+    #[test]
+    fn parse_display() {
+        assert_eq!(nat("0").to_string(), "0");
+        assert_eq!(nat("000042").to_string(), "42");
+        assert_eq!(
+            nat("123456789012345678901234567890").to_string(),
+            "123456789012345678901234567890"
+        );
+        assert_eq!(Natural::parse("12a"), None);
+        assert_eq!(Natural::parse(""), None);
+    }
+
+    #[test]
+    fn add_sub() {
+        let big = nat("18446744073709551615");
+        let one = Natural::from_u64(1);
+        assert_eq!(big.add(&one), nat("18446744073709551616"));
+        assert_eq!(big.add(&one).sub(&big), one);
+        assert_eq!(big.sub(&big), Natural::zero());
+    }
+
+    #[test]
+    fn mul_pow() {
+        let two = Natural::from_u64(2);
+        assert_eq!(two.pow(80).to_string(), "1208925819614629174706176");
+        assert_eq!(
+            nat("99999999999").mul(&nat("99999999999")),
+            nat("9999999999800000000001")
+        );
+        assert_eq!(two.pow(0), Natural::from_u64(1));
+    }
+
+    #[test]
+    fn divrem() {
+        let num = nat("1208925819614629174706176");
+        let (quo, rem) = num.divrem(&nat("1000000007"));
+        assert_eq!(quo, nat("1208925811152148"));
+        assert_eq!(rem, nat("496641140"));
+        let den = nat("340282366920938463463374607431768211455");
+        let (quo, rem) = den.mul(&num).add(&nat("42")).divrem(&den);
+        assert_eq!(quo, num);
+        assert_eq!(rem, nat("42"));
+    }
+
+    #[test]
+    fn gcd_result() {
+        let gcd = |a: u64, b: u64| {
+            Natural::from_u64(a).gcd(&Natural::from_u64(b)).to_u64()
+        };
+        assert_eq!(gcd(1029, 1071), Some(21));
+        assert_eq!(gcd(221, 782), Some(17));
+        assert_eq!(gcd(782, 32), Some(2));
+        assert_eq!(gcd(78752, 3), Some(1));
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(Natural::from_u64(99).nth_root(2), Natural::from_u64(9));
+        assert_eq!(Natural::from_u64(100).nth_root(2), Natural::from_u64(10));
+        assert_eq!(Natural::from_u64(26).nth_root(3), Natural::from_u64(2));
+        assert_eq!(
+            Natural::from_u64(2).pow(90).exact_root(3),
+            Some(Natural::from_u64(2).pow(30))
+        );
+        assert_eq!(Natural::from_u64(2).pow(91).exact_root(2), None);
+        assert_eq!(Natural::zero().exact_root(2), Some(Natural::zero()));
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(Natural::from_u64(42).to_f64(), 42.0);
+        assert_eq!(Natural::from_u64(2).pow(100).to_f64(), 2.0_f64.powi(100));
+        assert_eq!(
+            Natural::from_f64(2.0_f64.powi(100)),
+            Natural::from_u64(2).pow(100)
+        );
+    }
+}
@@ -0,0 +1,577 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   polynomial.rs                                      :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::imaginary::Imaginary;
+use super::rational::Rational;
+use super::OpResult;
+use crate::computor::ComputorError;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::{cmp, fmt, ops};
+
+pub type Monomial = BTreeMap<String, i32>;
+
+/// Largest number of terms a power may give.
+const MAX_TERMS: u64 = 300;
+
+/// `unknowns` keeps every name the polynomial was built from, even once its
+/// terms cancelled, so that `x * 0 = 0` is still an equation in `x`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    terms: BTreeMap<Monomial, Imaginary>,
+    unknowns: BTreeSet<String>,
+}
+
+impl Polynomial {
+    pub fn constant(val: Imaginary) -> Self {
+        let mut terms = BTreeMap::new();
+        if val != Imaginary::new(0.0, 0.0) {
+            terms.insert(Monomial::new(), val);
+        }
+        Polynomial {
+            terms,
+            unknowns: BTreeSet::new(),
+        }
+    }
+
+    pub fn unknown(id: &str) -> Self {
+        let mut monomial = Monomial::new();
+        monomial.insert(String::from(id), 1);
+        let mut terms = BTreeMap::new();
+        terms.insert(monomial, Imaginary::new(1.0, 0.0));
+        Polynomial {
+            terms,
+            unknowns: BTreeSet::from([String::from(id)]),
+        }
+    }
+
+    pub fn unknowns(&self) -> &BTreeSet<String> {
+        &self.unknowns
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn as_constant(&self) -> Option<Imaginary> {
+        match self.terms.len() {
+            0 => Some(Imaginary::new(0.0, 0.0)),
+            1 => self.terms.get(&Monomial::new()).cloned(),
+            _ => None,
+        }
+    }
+
+    pub fn univariate(&self, id: &str) -> Option<HashMap<i32, Imaginary>> {
+        let mut res: HashMap<i32, Imaginary> = HashMap::new();
+        for (monomial, coef) in self.terms.iter() {
+            let pow = match monomial.iter().next() {
+                None => 0,
+                Some((name, pow)) if name == id => *pow,
+                Some(_) => return None,
+            };
+            if monomial.len() > 1 {
+                return None;
+            }
+            res.insert(pow, coef.clone());
+        }
+        Some(res)
+    }
+
+    pub fn linear(&self) -> Option<(Vec<(String, Imaginary)>, Imaginary)> {
+        let mut terms: Vec<(String, Imaginary)> = Vec::new();
+        let mut constant = Imaginary::new(0.0, 0.0);
+        for (monomial, coef) in self.terms.iter() {
+            let mut powers = monomial.iter();
+            match (powers.next(), powers.next()) {
+                (None, _) => constant = coef.clone(),
+                (Some((id, 1)), None) => terms.push((id.clone(), coef.clone())),
+                _ => return None,
+            }
+        }
+        Some((terms, constant))
+    }
+
+    pub fn add(&self, other: &Polynomial) -> OpResult<Self> {
+        let mut res = self.clone();
+        for (monomial, coef) in other.terms.iter() {
+            res.push(monomial.clone(), coef.clone())?;
+        }
+        res.unknowns.extend(other.unknowns.iter().cloned());
+        Ok(res)
+    }
+
+    pub fn sub(&self, other: &Polynomial) -> OpResult<Self> {
+        self.add(&-other.clone())
+    }
+
+    pub fn mul(&self, other: &Polynomial) -> OpResult<Self> {
+        let mut res = Polynomial {
+            terms: BTreeMap::new(),
+            unknowns: self.unknowns.union(&other.unknowns).cloned().collect(),
+        };
+        for (mono_a, coef_a) in self.terms.iter() {
+            for (mono_b, coef_b) in other.terms.iter() {
+                res.push(product(mono_a, mono_b, 1)?, coef_a.mul(coef_b)?)?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// A single term divides anything, giving negative powers if needed.
+    pub fn div(&self, other: &Polynomial) -> OpResult<Self> {
+        let mut divisor = other.terms.iter();
+        let (mono_b, coef_b) = match (divisor.next(), divisor.next()) {
+            (None, _) => return Err(ComputorError::div_by_zero()),
+            (Some(term), None) => term,
+            _ => {
+                let (quotient, rem) = self.divrem(other)?;
+                if !rem.is_zero() {
+                    return Err(ComputorError::not_divisible(&rem.to_string()));
+                }
+                return Ok(quotient);
+            }
+        };
+        let mut res = Polynomial {
+            terms: BTreeMap::new(),
+            unknowns: self.unknowns.union(&other.unknowns).cloned().collect(),
+        };
+        for (mono_a, coef_a) in self.terms.iter() {
+            res.push(product(mono_a, mono_b, -1)?, coef_a.div(coef_b)?)?;
+        }
+        Ok(res)
+    }
+
+    /// Both must be in the same single unknown, with no negative power.
+    pub fn divrem(&self, other: &Polynomial) -> OpResult<(Self, Self)> {
+        if other.is_zero() {
+            return Err(ComputorError::div_by_zero());
+        }
+        let id = self.single_unknown(other)?;
+        let mut rem = self.dense(&id)?;
+        let divisor = other.dense(&id)?;
+        let lead = &divisor[divisor.len() - 1];
+        let zero = Imaginary::new(0.0, 0.0);
+        let mut quotient: Vec<Imaginary> = Vec::new();
+        while rem.len() >= divisor.len() {
+            let coef = rem[rem.len() - 1].div(lead)?;
+            let shift = rem.len() - divisor.len();
+            for (pos, val) in divisor.iter().enumerate() {
+                rem[shift + pos] = rem[shift + pos].sub(&coef.mul(val)?)?;
+            }
+            rem.pop();
+            quotient.push(coef);
+        }
+        quotient.reverse();
+        while rem.last() == Some(&zero) {
+            rem.pop();
+        }
+        let unknowns: BTreeSet<String> =
+            self.unknowns.union(&other.unknowns).cloned().collect();
+        Ok((
+            Polynomial::sparse(&id, quotient, unknowns.clone())?,
+            Polynomial::sparse(&id, rem, unknowns)?,
+        ))
+    }
+
+    /// Always monic.
+    pub fn gcd(&self, other: &Polynomial) -> OpResult<Self> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let rem = a.divrem(&b)?.1;
+            a = b;
+            b = rem;
+        }
+        let lead = match a.terms().first() {
+            Some((_, coef)) => (*coef).clone(),
+            None => return Ok(a),
+        };
+        a.unknowns.extend(b.unknowns);
+        a.div(&Polynomial::constant(lead))
+    }
+
+    pub fn substitute(&self, id: &str, val: &Polynomial) -> OpResult<Self> {
+        let mut res = Polynomial::constant(Imaginary::new(0.0, 0.0));
+        for (monomial, coef) in self.terms.iter() {
+            let mut rest = monomial.clone();
+            let pow = rest.remove(id);
+            let mut term = Polynomial {
+                terms: BTreeMap::from([(rest, coef.clone())]),
+                unknowns: BTreeSet::new(),
+            };
+            if let Some(pow) = pow {
+                term = term.mul(&val.pow(pow)?)?;
+            }
+            res = res.add(&term)?;
+        }
+        res.unknowns = self.unknowns.clone();
+        res.unknowns.remove(id);
+        res.unknowns.extend(val.unknowns.iter().cloned());
+        Ok(res)
+    }
+
+    pub fn terms(&self) -> Vec<(&Monomial, &Imaginary)> {
+        let mut terms: Vec<(&Monomial, &Imaginary)> =
+            self.terms.iter().collect();
+        terms.sort_by(|(mono_a, _), (mono_b, _)| canonical(mono_a, mono_b));
+        terms
+    }
+
+    fn single_unknown(&self, other: &Polynomial) -> OpResult<String> {
+        let names: BTreeSet<&String> = self
+            .terms
+            .keys()
+            .chain(other.terms.keys())
+            .flat_map(|monomial| monomial.keys())
+            .collect();
+        let mut names = names.into_iter();
+        match (names.next(), names.next()) {
+            (_, Some(_)) => Err(ComputorError::too_many_unknown()),
+            (Some(id), None) => Ok(id.clone()),
+            (None, None) => Ok(String::new()),
+        }
+    }
+
+    fn dense(&self, id: &str) -> OpResult<Vec<Imaginary>> {
+        let mut res: Vec<Imaginary> = Vec::new();
+        for (monomial, coef) in self.terms.iter() {
+            let pow = monomial.get(id).copied().unwrap_or(0);
+            if pow < 0 {
+                return Err(ComputorError::neg_pow_division());
+            }
+            let pow = pow as usize;
+            if res.len() <= pow {
+                res.resize(pow + 1, Imaginary::new(0.0, 0.0));
+            }
+            res[pow] = coef.clone();
+        }
+        Ok(res)
+    }
+
+    fn sparse(
+        id: &str,
+        coefs: Vec<Imaginary>,
+        unknowns: BTreeSet<String>,
+    ) -> OpResult<Self> {
+        let mut res = Polynomial {
+            terms: BTreeMap::new(),
+            unknowns,
+        };
+        for (pow, coef) in coefs.into_iter().enumerate() {
+            let mut monomial = Monomial::new();
+            if pow > 0 {
+                monomial.insert(String::from(id), pow as i32);
+            }
+            res.push(monomial, coef)?;
+        }
+        Ok(res)
+    }
+
+    pub fn pow(&self, power: i32) -> OpResult<Self> {
+        if power < 0 {
+            let one = Polynomial::constant(Imaginary::new(1.0, 0.0));
+            return match power.checked_neg() {
+                Some(power) => one.div(self)?.pow(power),
+                None => Err(ComputorError::overflow_abort()),
+            };
+        }
+        self.check_pow(power as u64)?;
+        let mut res = Polynomial {
+            terms: BTreeMap::new(),
+            unknowns: self.unknowns.clone(),
+        };
+        res.push(Monomial::new(), Imaginary::new(1.0, 0.0))?;
+        let mut base = self.clone();
+        let mut pow = power;
+
+        while pow > 0 {
+            if pow % 2 == 1 {
+                res = res.mul(&base)?;
+            }
+            pow /= 2;
+            if pow > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Refuses powers whose result would be too long to compute, bounding
+    /// its terms, exponents and coefficient sizes.
+    fn check_pow(&self, power: u64) -> OpResult<()> {
+        if power < 2 {
+            return Ok(());
+        }
+        let count = self.terms.len() as u64;
+        let mut terms: u64 = 1;
+        for k in 1..count {
+            terms = terms.saturating_mul(power + k) / k;
+            if terms > MAX_TERMS {
+                return Err(ComputorError::overflow_abort());
+            }
+        }
+        let degree = self
+            .terms
+            .keys()
+            .flat_map(|monomial| monomial.values())
+            .map(|pow| u64::from(pow.unsigned_abs()))
+            .max()
+            .unwrap_or(0);
+        let units = [(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)]
+            .map(|(re, im)| Imaginary::new(re, im));
+        let bits = self
+            .terms
+            .values()
+            .filter(|val| !units.contains(val))
+            .map(|val| {
+                cmp::max(val.get_real().bits(), val.get_irreal().bits()) + 1
+            })
+            .max()
+            .unwrap_or(0)
+            + u64::from(64 - (count - 1).leading_zeros());
+        if degree.saturating_mul(power) > i32::MAX as u64
+            || bits.saturating_mul(power) > Rational::max_bits()
+        {
+            return Err(ComputorError::overflow_abort());
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, monomial: Monomial, coef: Imaginary) -> OpResult<()> {
+        let zero = Imaginary::new(0.0, 0.0);
+        let sum = match self.terms.remove(&monomial) {
+            Some(prev) => prev.add(&coef)?,
+            None => coef,
+        };
+        if sum != zero {
+            self.terms.insert(monomial, sum);
+        }
+        Ok(())
+    }
+}
+
+fn total(monomial: &Monomial) -> i64 {
+    monomial.values().map(|pow| i64::from(*pow)).sum()
+}
+
+fn product(
+    mono_a: &Monomial,
+    mono_b: &Monomial,
+    sign: i32,
+) -> OpResult<Monomial> {
+    let mut res = mono_a.clone();
+    for (id, pow) in mono_b.iter() {
+        let sum = pow
+            .checked_mul(sign)
+            .and_then(|pow| pow.checked_add(*res.get(id).unwrap_or(&0)));
+        match sum {
+            None => return Err(ComputorError::overflow_abort()),
+            Some(0) => res.remove(id),
+            Some(sum) => res.insert(id.clone(), sum),
+        };
+    }
+    Ok(res)
+}
+
+/// Highest degree first, then unknowns in alphabetical order.
+fn canonical(mono_a: &Monomial, mono_b: &Monomial) -> cmp::Ordering {
+    let names: BTreeSet<&String> = mono_a.keys().chain(mono_b.keys()).collect();
+    let by_name = names.into_iter().map(|id| {
+        let pow_a = mono_a.get(id).unwrap_or(&0);
+        let pow_b = mono_b.get(id).unwrap_or(&0);
+        pow_b.cmp(pow_a)
+    });
+    let mut res = total(mono_b).cmp(&total(mono_a));
+    for order in by_name {
+        res = res.then(order);
+    }
+    res
+}
+
+fn monomial_str(monomial: &Monomial) -> String {
+    monomial
+        .iter()
+        .map(|(id, pow)| match pow {
+            1 => id.clone(),
+            _ => format!("{}^{}", id, pow),
+        })
+        .collect::<Vec<_>>()
+        .join(" * ")
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        let mut print = String::new();
+        for (monomial, coef) in self.terms().into_iter() {
+            let negative = match coef.get_real() {
+                real if real.is_zero() => !coef.get_irreal().is_positive(),
+                real => coef.is_real() && !real.is_positive(),
+            };
+            let coef = if negative {
+                -coef.clone()
+            } else {
+                coef.clone()
+            };
+            let both = !coef.is_real() && !coef.get_real().is_zero();
+            let coef_str = match both {
+                true => format!("({})", coef),
+                false => coef.to_string(),
+            };
+            let term = match (monomial.is_empty(), coef_str.as_str()) {
+                (true, _) => coef_str,
+                (false, "1") => monomial_str(monomial),
+                (false, _) => {
+                    format!("{} * {}", coef_str, monomial_str(monomial))
+                }
+            };
+            print = match (print.is_empty(), negative) {
+                (true, true) => format!("- {}", term),
+                (true, false) => term,
+                (false, true) => format!("{} - {}", print, term),
+                (false, false) => format!("{} + {}", print, term),
+            };
+        }
+        write!(f, "{}", print)
+    }
+}
+
+impl ops::Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(mut self) -> Self::Output {
+        for (_, coef) in self.terms.iter_mut() {
+            *coef = -coef.clone();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod operator {
+    use super::Polynomial;
+    use crate::types::Imaginary;
+
+    fn val(real: f64) -> Polynomial {
+        Polynomial::constant(Imaginary::new(real, 0.0))
+    }
+
+    #[test]
+    fn add_unknowns() {
+        let x = Polynomial::unknown("x");
+        let y = Polynomial::unknown("y");
+        let res = y.add(&x).unwrap().add(&val(1.0)).unwrap();
+
+        assert_eq!(res.to_string(), "x + y + 1");
+        assert_eq!(res.sub(&x).unwrap().to_string(), "y + 1");
+        assert_eq!(
+            x.sub(&x).unwrap().as_constant(),
+            Some(Imaginary::new(0.0, 0.0))
+        );
+        assert_eq!(x.sub(&x).unwrap().unknowns().len(), 1);
+    }
+
+    #[test]
+    fn mul_unknowns() {
+        let x = Polynomial::unknown("x");
+        let y = Polynomial::unknown("y");
+        let sum = x.add(&y).unwrap();
+        let diff = x.sub(&y).unwrap();
+
+        assert_eq!(x.mul(&y).unwrap().to_string(), "x * y");
+        assert_eq!(sum.mul(&diff).unwrap().to_string(), "x^2 - y^2");
+        assert_eq!(sum.pow(2).unwrap().to_string(), "x^2 + 2 * x * y + y^2");
+        assert!(x.mul(&val(0.0)).unwrap().is_zero());
+    }
+
+    #[test]
+    fn div_unknowns() {
+        let x = Polynomial::unknown("x");
+        let y = Polynomial::unknown("y");
+        let x_sq = x.pow(2).unwrap().mul(&val(4.0)).unwrap();
+
+        assert_eq!(x_sq.div(&x).unwrap().to_string(), "4 * x");
+        assert_eq!(x.div(&y).unwrap().to_string(), "x * y^-1");
+        assert!(x.div(&x.add(&y).unwrap()).is_err());
+        assert!(x.div(&val(0.0)).is_err());
+    }
+
+    #[test]
+    fn oversized_power() {
+        let x = Polynomial::unknown("x");
+        let y = Polynomial::unknown("y");
+        let binomial = x.add(&val(1.0)).unwrap();
+        let trinomial = binomial.add(&y).unwrap();
+        let big = x.pow(i32::MAX).unwrap();
+
+        assert_eq!(binomial.pow(299).unwrap().terms().len(), 300);
+        assert!(binomial.pow(i32::MAX).is_err());
+        assert!(binomial.pow(i32::MIN).is_err());
+        assert!(trinomial.pow(60).is_err());
+        assert!(big.mul(&x).is_err());
+        assert!(big.pow(2).is_err());
+    }
+
+    #[test]
+    fn reduce_to_one_unknown() {
+        let x = Polynomial::unknown("x");
+        let eq = x.pow(2).unwrap().sub(&val(4.0)).unwrap();
+        let reduced = eq.univariate("x").unwrap();
+
+        assert_eq!(reduced.get(&2), Some(&Imaginary::new(1.0, 0.0)));
+        assert_eq!(reduced.get(&0), Some(&Imaginary::new(-4.0, 0.0)));
+        assert_eq!(eq.univariate("y"), None);
+        assert_eq!(eq.linear(), None);
+    }
+
+    #[test]
+    fn euclidean_division() {
+        let x = Polynomial::unknown("x");
+        let num = x.pow(3).unwrap().add(&val(2.0)).unwrap();
+        let den = x.sub(&val(1.0)).unwrap();
+        let (quotient, rem) = num.divrem(&den).unwrap();
+
+        assert_eq!(quotient.to_string(), "x^2 + x + 1");
+        assert_eq!(rem.as_constant(), Some(Imaginary::new(3.0, 0.0)));
+        assert!(num.div(&den).is_err());
+        assert_eq!(num.sub(&val(3.0)).unwrap().div(&den).unwrap(), quotient);
+    }
+
+    #[test]
+    fn greatest_common_divisor() {
+        let x = Polynomial::unknown("x");
+        let square = x.pow(2).unwrap().sub(&val(1.0)).unwrap();
+        let other = x.mul(&val(2.0)).unwrap().add(&val(2.0)).unwrap();
+
+        assert_eq!(square.gcd(&other).unwrap().to_string(), "x + 1");
+        assert_eq!(square.gcd(&x).unwrap().to_string(), "1");
+    }
+
+    #[test]
+    fn substitute_unknown() {
+        let x = Polynomial::unknown("x");
+        let y = Polynomial::unknown("y");
+        let square = x.pow(2).unwrap().add(&val(1.0)).unwrap();
+
+        assert_eq!(
+            square.substitute("x", &val(3.0)).unwrap().as_constant(),
+            Some(Imaginary::new(10.0, 0.0))
+        );
+        assert_eq!(
+            square
+                .substitute("x", &y.add(&val(1.0)).unwrap())
+                .unwrap()
+                .to_string(),
+            "y^2 + 2 * y + 2"
+        );
+    }
+}
@@ -10,197 +10,298 @@
 /*                                                                            */
 /* ************************************************************************** */
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{cmp, fmt, ops};
 
-use std::i32::{MAX as I32_MAX, MIN as I32_MIN};
-
-use super::{read_overflow, OpResult};
+use super::{Natural, OpResult};
 use crate::computor::ComputorError;
 
-const PRECISION: usize = 10;
+const FLOAT_PRECISION: i32 = 10;
 const EPSILON: f64 = 0.0000001;
+const MAX_BITS: u64 = 1 << 20;
+
+static PRECISION: AtomicUsize = AtomicUsize::new(10);
 
-#[derive(Eq, Ord, Copy, Clone, Debug)]
+#[derive(Eq, Clone, Debug)]
 pub struct Rational {
     posit: bool,
-    num: u64,
-    den: u64,
+    num: Natural,
+    den: Natural,
 }
 
 impl Rational {
     pub fn new(param: f64) -> Self {
         if param != 0.0 {
-            let mut den = dec_div(param.abs());
-            let mut num = (param.abs() * den as f64).round() as u64;
-
-            simplify_gcd(&mut num, &mut den);
-            Rational {
-                posit: param >= 0.0,
-                num,
-                den: den,
-            }
+            let den = dec_div(param.abs());
+            let num = Natural::from_f64((param.abs() * den as f64).round());
+
+            Rational::reduce(param >= 0.0, num, Natural::from_u64(den))
         } else {
-            Rational {
-                posit: true,
-                num: 0,
-                den: 1,
-            }
+            Rational::zero()
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (mantissa, exp) = match raw.find('e') {
+            Some(pos) => (&raw[..pos], raw[pos + 1..].parse::<i64>().ok()?),
+            None => (raw, 0),
+        };
+        let (int, fract) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+        if int.len() + fract.len() == 0
+            || !(int.bytes().chain(fract.bytes())).all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let shift = exp - fract.len() as i64;
+        if shift.unsigned_abs().saturating_mul(4) > MAX_BITS {
+            return None;
         }
+        let ten_pow = Natural::from_u64(10).pow(shift.unsigned_abs() as u32);
+        let mut num = Natural::parse(&format!("{}{}", int, fract))?;
+        let mut den = Natural::from_u64(1);
+        if shift >= 0 {
+            num = num.mul(&ten_pow);
+        } else {
+            den = ten_pow;
+        }
+        Some(Rational::reduce(true, num, den))
     }
 
     pub fn zero() -> Self {
         Rational {
             posit: true,
-            num: 0,
-            den: 1,
+            num: Natural::zero(),
+            den: Natural::from_u64(1),
         }
     }
 
+    pub fn from_natural(posit: bool, num: Natural, den: Natural) -> Self {
+        Rational::reduce(posit, num, den)
+    }
+
+    fn reduce(posit: bool, mut num: Natural, mut den: Natural) -> Self {
+        simplify_gcd(&mut num, &mut den);
+        Rational {
+            posit: posit || num.is_zero(),
+            num,
+            den,
+        }
+    }
+
+    pub fn precision() -> usize {
+        PRECISION.load(Ordering::Relaxed)
+    }
+
+    pub fn set_precision(precision: usize) {
+        PRECISION.store(precision, Ordering::Relaxed);
+    }
+
     pub fn is_int(&self) -> bool {
-        self.den == 1
+        self.den.is_one()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.posit
+    }
+
+    pub fn num(&self) -> &Natural {
+        &self.num
+    }
+
+    pub fn den(&self) -> &Natural {
+        &self.den
+    }
+
+    pub fn bits(&self) -> u64 {
+        cmp::max(self.num.bits(), self.den.bits())
+    }
+
+    pub fn max_bits() -> u64 {
+        MAX_BITS
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            posit: true,
+            num: self.num.clone(),
+            den: self.den.clone(),
+        }
+    }
+
+    pub fn exact_root(&self, n: u32) -> Option<Rational> {
+        if !self.posit && n.is_multiple_of(2) {
+            return None;
+        }
+        Some(Rational {
+            posit: self.posit,
+            num: self.num.exact_root(n)?,
+            den: self.den.exact_root(n)?,
+        })
+    }
+
+    pub fn floor(&self) -> Rational {
+        let (quo, rem) = self.num.divrem(&self.den);
+        let int = if self.posit || rem.is_zero() {
+            quo
+        } else {
+            quo.add(&Natural::from_u64(1))
+        };
+        Rational::reduce(self.posit, int, Natural::from_u64(1))
+    }
+
+    pub fn ceil(&self) -> Rational {
+        -(-self.clone()).floor()
+    }
+
+    pub fn round(&self) -> Rational {
+        let (quo, rem) = self.num.divrem(&self.den);
+        let int = if rem.shl(1) >= self.den {
+            quo.add(&Natural::from_u64(1))
+        } else {
+            quo
+        };
+        Rational::reduce(self.posit, int, Natural::from_u64(1))
     }
 
     pub fn get_val(&self) -> f64 {
-        self.num as f64 / self.den as f64 * if self.posit { 1.0 } else { -1.0 }
+        let sign = if self.posit { 1.0 } else { -1.0 };
+        let bits = self.bits();
+        if bits <= 1000 {
+            return self.num.to_f64() / self.den.to_f64() * sign;
+        }
+        let shift = (bits - 1000) as u32;
+        self.num.shr(shift).to_f64() / self.den.shr(shift).to_f64() * sign
     }
 
     pub fn simplify(&mut self) {
         simplify_gcd(&mut self.num, &mut self.den);
-        if self.num == 0 {
+        if self.num.is_zero() {
             self.posit = true
         }
     }
 
     pub fn add(&self, other: &Rational) -> OpResult<Self> {
-        let sig;
-        let left = read_overflow(self.num.overflowing_mul(other.den))?;
-        let right = read_overflow(other.num.overflowing_mul(self.den))?;
-        let mut num =
-            if (self.posit && other.posit) || !(self.posit || other.posit) {
-                sig = self.posit && other.posit;
-                read_overflow(left.overflowing_add(right))?
-            } else {
-                let val_a = cmp::max(left, right);
-                let val_b = cmp::min(left, right);
-                sig = if val_a == left {
-                    self.posit
-                } else {
-                    other.posit
-                };
-                read_overflow(val_a.overflowing_sub(val_b))?
-            };
-        let mut den = read_overflow(self.den.overflowing_mul(other.den))?;
-
-        simplify_gcd(&mut num, &mut den);
-        Ok(Rational {
-            posit: sig || num == 0,
-            num,
-            den,
-        })
+        let left = self.num.mul(&other.den);
+        let right = other.num.mul(&self.den);
+        let den = self.den.mul(&other.den);
+        if self.posit == other.posit {
+            return Ok(Rational::reduce(self.posit, left.add(&right), den));
+        }
+        if left >= right {
+            Ok(Rational::reduce(self.posit, left.sub(&right), den))
+        } else {
+            Ok(Rational::reduce(other.posit, right.sub(&left), den))
+        }
     }
 
     pub fn sub(&self, other: &Rational) -> OpResult<Self> {
-        let negated = -*other;
+        let negated = -other.clone();
         self.add(&negated)
     }
 
     pub fn mul(&self, other: &Rational) -> OpResult<Self> {
-        let mut num = read_overflow(self.num.overflowing_mul(other.num))?;
-        let mut den = read_overflow(self.den.overflowing_mul(other.den))?;
-
-        simplify_gcd(&mut num, &mut den);
-        Ok(Rational {
-            posit: num == 0
-                || (self.posit && other.posit)
-                || !(self.posit || other.posit),
-            num,
-            den,
-        })
+        Ok(Rational::reduce(
+            self.posit == other.posit,
+            self.num.mul(&other.num),
+            self.den.mul(&other.den),
+        ))
     }
 
     pub fn div(&self, other: &Rational) -> OpResult<Self> {
-        if other.num == 0 {
+        if other.num.is_zero() {
             return Err(ComputorError::div_by_zero());
         }
-        let mut num = read_overflow(self.num.overflowing_mul(other.den))?;
-        let mut den = read_overflow(self.den.overflowing_mul(other.num))?;
-
-        simplify_gcd(&mut num, &mut den);
-        Ok(Rational {
-            posit: num == 0
-                || (self.posit && other.posit)
-                || !(self.posit || other.posit),
-            num,
-            den,
-        })
+        Ok(Rational::reduce(
+            self.posit == other.posit,
+            self.num.mul(&other.den),
+            self.den.mul(&other.num),
+        ))
     }
 
     pub fn rem(&self, other: &Rational) -> OpResult<Self> {
-        if other.num == 0 {
+        if other.num.is_zero() {
             return Err(ComputorError::div_by_zero());
         }
         if self < other {
-            Ok(*self)
+            Ok(self.clone())
         } else {
-            let left = read_overflow(self.num.overflowing_mul(other.den))?;
-            let right = read_overflow(other.num.overflowing_mul(self.den))?;
-            let mut num = read_overflow(left.overflowing_rem(right))?;
-            let mut den = read_overflow(other.den.overflowing_mul(self.den))?;
-
-            simplify_gcd(&mut num, &mut den);
-            Ok(Rational {
-                posit: num == 0 || self.posit,
-                num,
-                den,
-            })
+            let left = self.num.mul(&other.den);
+            let right = other.num.mul(&self.den);
+            let (_, num) = left.divrem(&right);
+            Ok(Rational::reduce(self.posit, num, other.den.mul(&self.den)))
         }
     }
 
     pub fn pow(&self, power: i32) -> OpResult<Self> {
-        let mut num: u64;
-        let mut den: u64;
-        let pow: u32;
-        match power {
-            0 => {
-                return Ok(Rational {
-                    posit: true,
-                    num: 1,
-                    den: 1,
-                });
-            }
-            0..=I32_MAX => {
-                pow = power as u32;
-                num = read_overflow(self.num.overflowing_pow(pow))?;
-                den = read_overflow(self.den.overflowing_pow(pow))?;
-            }
-            I32_MIN..=0 => {
-                pow = -power as u32;
-                num = read_overflow(self.den.overflowing_pow(pow))?;
-                den = read_overflow(self.num.overflowing_pow(pow))?;
+        let pow = power.unsigned_abs();
+        if pow == 0 {
+            return Ok(Rational::from_natural(
+                true,
+                Natural::from_u64(1),
+                Natural::from_u64(1),
+            ));
+        }
+        if self.bits().saturating_mul(pow as u64) > MAX_BITS {
+            return Err(ComputorError::overflow_abort());
+        }
+        let (num, den) = if power > 0 {
+            (self.num.pow(pow), self.den.pow(pow))
+        } else {
+            if self.num.is_zero() {
+                return Err(ComputorError::div_by_zero());
             }
+            (self.den.pow(pow), self.num.pow(pow))
         };
-        simplify_gcd(&mut num, &mut den);
-        Ok(Rational {
-            posit: self.posit || pow % 2 == 0,
+        Ok(Rational::reduce(
+            self.posit || pow.is_multiple_of(2),
             num,
             den,
-        })
+        ))
     }
 }
 
 impl fmt::Display for Rational {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let sign = if self.posit { "" } else { "- " };
-        if self.den != 0 {
-            let float_value: f64 = self.num as f64 / self.den as f64;
-            let fract_len = dec_len(float_value);
-            if fract_len <= PRECISION {
-                write!(f, "{}{:.*}", sign, fract_len, float_value)
-            } else {
-                write!(f, "{}{:.*}..", sign, PRECISION, float_value)
+        if self.den.is_zero() {
+            return write!(f, "{}inf", sign);
+        }
+        let (int, rem) = self.num.divrem(&self.den);
+        if rem.is_zero() {
+            return write!(f, "{}{}", sign, int);
+        }
+        let precision = Rational::precision();
+        let scale = Natural::from_u64(10).pow(precision as u32);
+        let (mut fract, fract_rem) = rem.mul(&scale).divrem(&self.den);
+        if fract_rem.is_zero() {
+            let digits = format!("{:0>width$}", fract, width = precision);
+            return write!(
+                f,
+                "{}{}.{}",
+                sign,
+                int,
+                digits.trim_end_matches('0')
+            );
+        }
+        let mut int = int;
+        if fract_rem.shl(1) >= self.den {
+            fract = fract.add(&Natural::from_u64(1));
+            if fract == scale {
+                fract = Natural::zero();
+                int = int.add(&Natural::from_u64(1));
             }
+        }
+        if precision == 0 {
+            write!(f, "{}{}..", sign, int)
         } else {
-            write!(f, "{}inf", sign)
+            write!(f, "{}{}.{:0>width$}..", sign, int, fract, width = precision)
         }
     }
 }
@@ -211,55 +312,37 @@ impl cmp::PartialEq for Rational {
     }
 }
 
-impl cmp::PartialOrd for Rational {
-    fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+impl cmp::Ord for Rational {
+    fn cmp(&self, rhs: &Self) -> cmp::Ordering {
+        let left = self.num.mul(&rhs.den);
+        let right = rhs.num.mul(&self.den);
         match (self.posit, rhs.posit) {
-            (left_sign, right_sign) if left_sign && !right_sign => {
-                Some(cmp::Ordering::Greater)
-            }
-            (left_sign, right_sign) if !left_sign && right_sign => {
-                Some(cmp::Ordering::Less)
-            }
-            (left_sign, right_sign) if left_sign && right_sign => {
-                Some((self.num * rhs.den).cmp(&(self.den * rhs.num)))
-            }
-            (left_sign, right_sign) if !left_sign && !right_sign => {
-                Some((rhs.num * self.den).cmp(&(rhs.den * self.num)))
-            }
-            _ => None,
+            (true, false) => cmp::Ordering::Greater,
+            (false, true) => cmp::Ordering::Less,
+            (true, true) => left.cmp(&right),
+            (false, false) => right.cmp(&left),
         }
     }
 }
 
+impl cmp::PartialOrd for Rational {
+    fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
 impl ops::Neg for Rational {
     type Output = Rational;
 
     fn neg(mut self) -> Self::Output {
-        self.posit = self.num == 0 || !self.posit;
+        self.posit = self.num.is_zero() || !self.posit;
         self
     }
 }
 
-fn dec_len(nb: f64) -> usize {
-    let mut len = PRECISION + 1;
-    let mut ten_power: f64 = 10.0_f64.powi(PRECISION as i32 + 1);
-    let mut limited = (nb * ten_power).round();
-    let mut fract = limited.fract();
-    while len > 0 && fract < EPSILON {
-        len -= 1;
-        limited = limited / 10.0;
-        fract = limited.fract();
-        ten_power = ten_power / 10.0;
-    }
-    if fract < EPSILON {
-        len
-    } else {
-        len + 1
-    }
-}
-
+#[allow(clippy::assign_op_pattern, clippy::needless_return)]
 fn dec_div(nb: f64) -> u64 {
-    let mut ten_power: f64 = 10.0_f64.powi(PRECISION as i32 + 1);
+    let mut ten_power: f64 = 10.0_f64.powi(FLOAT_PRECISION + 1);
     let mut limited = (nb * ten_power).round();
     let mut fract = limited.fract();
     while ten_power >= 1.0 && fract < EPSILON {
@@ -270,16 +353,11 @@ fn dec_div(nb: f64) -> u64 {
     return (ten_power * 10.0) as u64;
 }
 
-fn simplify_gcd(num: &mut u64, den: &mut u64) {
-    let div = gcd(*num, *den);
-    *num = *num / div;
-    *den = *den / div;
-}
-
-fn gcd(val_a: u64, val_b: u64) -> u64 {
-    match val_b {
-        0 => val_a,
-        _ => gcd(val_b, val_a % val_b),
+fn simplify_gcd(num: &mut Natural, den: &mut Natural) {
+    let div = num.gcd(den);
+    if !div.is_one() && !div.is_zero() {
+        *num = num.divrem(&div).0;
+        *den = den.divrem(&div).0;
     }
 }
 
@@ -290,8 +368,12 @@ mod constructor {
     fn new_zero() {
         let zero = Rational::zero();
         assert!(zero.posit, "Zero is posite.");
-        assert_eq!(zero.num, 0, "Zero numerator is not null");
-        assert_eq!(zero.den, 1, "Zero denominator should never be null");
+        assert_eq!(zero.num.to_u64(), Some(0), "Zero numerator is not null");
+        assert_eq!(
+            zero.den.to_u64(),
+            Some(1),
+            "Zero denominator should never be null"
+        );
     }
 
     #[test]
@@ -299,8 +381,8 @@ mod constructor {
         let value = Rational::new(0.0);
 
         assert!(value.posit, "Float invalid sign");
-        assert_eq!(value.num, 0, "Float invalid numerator");
-        assert_eq!(value.den, 1, "Float invalid denominator");
+        assert_eq!(value.num.to_u64(), Some(0), "Float invalid numerator");
+        assert_eq!(value.den.to_u64(), Some(1), "Float invalid denominator");
     }
 
     #[test]
@@ -308,8 +390,8 @@ mod constructor {
         let value = Rational::new(-42.42);
 
         assert!(!value.posit, "Float invalid sign");
-        assert_eq!(value.num, 2121, "Float invalid numerator");
-        assert_eq!(value.den, 50, "Float invalid denominator");
+        assert_eq!(value.num.to_u64(), Some(2121), "Float invalid numerator");
+        assert_eq!(value.den.to_u64(), Some(50), "Float invalid denominator");
     }
 
     #[test]
@@ -317,8 +399,8 @@ mod constructor {
         let value = Rational::new(123.0);
 
         assert!(value.posit, "Float invalid sign");
-        assert_eq!(value.num, 123, "Float invalid numerator");
-        assert_eq!(value.den, 1, "Float invalid denominator");
+        assert_eq!(value.num.to_u64(), Some(123), "Float invalid numerator");
+        assert_eq!(value.den.to_u64(), Some(1), "Float invalid denominator");
     }
 
     #[test]
@@ -326,8 +408,12 @@ mod constructor {
         let value = Rational::new(-99999999.9);
 
         assert!(!value.posit, "Float invalid sign");
-        assert_eq!(value.num, 999999999, "Float invalid numerator");
-        assert_eq!(value.den, 10, "Float invalid denominator");
+        assert_eq!(
+            value.num.to_u64(),
+            Some(999999999),
+            "Float invalid numerator"
+        );
+        assert_eq!(value.den.to_u64(), Some(10), "Float invalid denominator");
     }
 
     #[test]
@@ -335,8 +421,12 @@ mod constructor {
         let value = Rational::new(111111111.1);
 
         assert!(value.posit, "Float invalid sign");
-        assert_eq!(value.num, 1111111111, "Float invalid numerator");
-        assert_eq!(value.den, 10, "Float invalid denominator");
+        assert_eq!(
+            value.num.to_u64(),
+            Some(1111111111),
+            "Float invalid numerator"
+        );
+        assert_eq!(value.den.to_u64(), Some(10), "Float invalid denominator");
     }
 
     #[test]
@@ -344,8 +434,12 @@ mod constructor {
         let value = Rational::new(-7.77777777);
 
         assert!(!value.posit, "Float invalid sign");
-        assert_eq!(value.num, 777777777, "Float invalid numerator");
-        assert_eq!(value.den, 100000000, "Float invalid den");
+        assert_eq!(
+            value.num.to_u64(),
+            Some(777777777),
+            "Float invalid numerator"
+        );
+        assert_eq!(value.den.to_u64(), Some(100000000), "Float invalid den");
     }
 
     #[test]
@@ -353,12 +447,70 @@ mod constructor {
         let value = Rational::new(3.333333333);
 
         assert!(value.posit, "Float invalid sign");
-        assert_eq!(value.num, 3333333333, "Float invalid numerator");
-        assert_eq!(value.den, 1000000000, "Float invalid den");
+        assert_eq!(
+            value.num.to_u64(),
+            Some(3333333333),
+            "Float invalid numerator"
+        );
+        assert_eq!(value.den.to_u64(), Some(1000000000), "Float invalid den");
+    }
+
+    #[test]
+    fn parse_exact() {
+        let value = Rational::parse("0.1").unwrap();
+        assert_eq!(value.num.to_u64(), Some(1), "Parse invalid numerator");
+        assert_eq!(value.den.to_u64(), Some(10), "Parse invalid denominator");
+
+        let value = Rational::parse("123456789.123456789").unwrap();
+        assert_eq!(value.to_string(), "123456789.123456789");
+        assert_eq!(value.num.to_string(), "123456789123456789");
+        assert_eq!(value.den.to_string(), "1000000000");
+
+        let value = Rational::parse("1e-30").unwrap();
+        assert!(value.num.is_one(), "Parse invalid numerator");
+        assert_eq!(value.den.to_string(), format!("1{}", "0".repeat(30)));
+
+        let value = Rational::parse("2.5e3").unwrap();
+        assert_eq!(value.num.to_u64(), Some(2500), "Parse invalid numerator");
+        assert!(value.den.is_one(), "Parse invalid denominator");
+    }
+
+    #[test]
+    fn exact_root() {
+        let value = Rational::parse("2.25").unwrap();
+        assert_eq!(value.exact_root(2), Rational::parse("1.5"));
+        assert_eq!((-value.clone()).exact_root(2), None);
+        assert_eq!(Rational::parse("2").unwrap().exact_root(2), None);
+        let value = -Rational::parse("0.008").unwrap();
+        assert_eq!(value.exact_root(3), Some(-Rational::parse("0.2").unwrap()));
+    }
+
+    #[test]
+    fn rounding() {
+        let value = Rational::parse("2.5").unwrap();
+        assert_eq!(value.floor(), Rational::new(2.0));
+        assert_eq!(value.ceil(), Rational::new(3.0));
+        assert_eq!(value.round(), Rational::new(3.0));
+        let value = -value;
+        assert_eq!(value.floor(), Rational::new(-3.0));
+        assert_eq!(value.ceil(), Rational::new(-2.0));
+        assert_eq!(value.round(), Rational::new(-3.0));
+        assert_eq!(Rational::new(-0.4).round(), Rational::zero());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(Rational::parse(""), None);
+        assert_eq!(Rational::parse("."), None);
+        assert_eq!(Rational::parse("1.2.3"), None);
+        assert_eq!(Rational::parse("1e"), None);
+        assert_eq!(Rational::parse("1e+-2"), None);
+        assert_eq!(Rational::parse("1e999999999"), None);
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision, clippy::nonminimal_bool)]
 mod operator {
     use super::Rational;
 
@@ -542,6 +694,7 @@ mod operator {
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod pow {
     use super::Rational;
 
@@ -571,16 +724,3 @@ mod pow {
         assert_eq!(pos.pow(5).unwrap().get_val(), 454507357.5715545949);
     }
 }
-
-#[cfg(test)]
-mod other {
-    use super::gcd;
-
-    #[test]
-    fn gcd_result() {
-        assert_eq!(gcd(1029, 1071), 21);
-        assert_eq!(gcd(221, 782), 17);
-        assert_eq!(gcd(782, 32), 2);
-        assert_eq!(gcd(78752, 3), 1);
-    }
-}
@@ -0,0 +1,153 @@
+/* ************************************************************************** */
+/*                                                                            */
+/*                                                        :::      ::::::::   */
+/*   surd.rs                                            :+:      :+:    :+:   */
+/*                                                    +:+ +:+         +:+     */
+/*   By: gsmith <gsmith@student.42.fr>              +#+  +:+       +#+        */
+/*                                                +#+#+#+#+#+   +#+           */
+/*   Created: 2026/10/18 10:00:00 by gsmith            #+#    #+#             */
+/*   Updated: 2026/10/18 10:00:00 by gsmith           ###   ########.fr       */
+/*                                                                            */
+/* ************************************************************************** */
+
+use super::{Natural, OpResult, Rational};
+
+use std::fmt;
+
+const TRIAL_LIMIT: u64 = 100_000;
+
+/// Quadratic irrational `rational + coef * √radicand`, the radicand
+/// being square free and above one. `imaginary` stands for `√-radicand`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Surd {
+    rational: Rational,
+    coef: Rational,
+    radicand: Natural,
+    imaginary: bool,
+}
+
+impl Surd {
+    pub fn sqrt(val: &Rational) -> Option<Surd> {
+        // √(n/d) = √(n * d) / d
+        let (square, radicand) = split_square(&val.num().mul(val.den()));
+        if radicand.is_one() || radicand.is_zero() {
+            return None;
+        }
+        Some(Surd {
+            rational: Rational::zero(),
+            coef: Rational::from_natural(true, square, val.den().clone()),
+            radicand,
+            imaginary: !val.is_positive(),
+        })
+    }
+
+    pub fn affine(&self, scale: &Rational, shift: &Rational) -> OpResult<Surd> {
+        Ok(Surd {
+            rational: self.rational.mul(scale)?.add(shift)?,
+            coef: self.coef.mul(scale)?,
+            radicand: self.radicand.clone(),
+            imaginary: self.imaginary,
+        })
+    }
+
+    pub fn conjugate(&self) -> Surd {
+        Surd {
+            coef: -self.coef.clone(),
+            ..self.clone()
+        }
+    }
+}
+
+/// Split `val` into `square^2 * rest`, `rest` being square free unless
+/// it keeps a square of a prime above the trial division bound.
+fn split_square(val: &Natural) -> (Natural, Natural) {
+    let mut square = Natural::from_u64(1);
+    let mut rest = Natural::from_u64(1);
+    let mut left = val.clone();
+    let mut prime = 2;
+    while prime < TRIAL_LIMIT && Natural::from_u64(prime * prime) <= left {
+        let factor = Natural::from_u64(prime);
+        let mut count = 0;
+        loop {
+            let (quo, rem) = left.divrem(&factor);
+            if !rem.is_zero() {
+                break;
+            }
+            left = quo;
+            count += 1;
+        }
+        square = square.mul(&factor.pow(count / 2));
+        if count % 2 == 1 {
+            rest = rest.mul(&factor);
+        }
+        prime += if prime == 2 { 1 } else { 2 };
+    }
+    match left.exact_root(2) {
+        Some(root) => square = square.mul(&root),
+        None => rest = rest.mul(&left),
+    }
+    (square, rest)
+}
+
+impl fmt::Display for Surd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rat_den = self.rational.den();
+        let coef_den = self.coef.den();
+        let den = rat_den.divrem(&rat_den.gcd(coef_den)).0.mul(coef_den);
+        let den = Rational::from_natural(true, den, Natural::from_u64(1));
+        let (rational, coef) =
+            match (self.rational.mul(&den), self.coef.mul(&den)) {
+                (Ok(rational), Ok(coef)) => (rational, coef),
+                _ => return Err(fmt::Error),
+            };
+        let unit = if self.imaginary { "i" } else { "" };
+        let root = match coef.abs().num().is_one() {
+            true => format!("{}√{}", unit, self.radicand),
+            false => format!("{}{}√{}", coef.abs(), unit, self.radicand),
+        };
+        let sign = if coef.is_positive() { "+" } else { "-" };
+        let num = match (rational.is_zero(), coef.is_positive()) {
+            (true, true) => root,
+            (true, false) => format!("- {}", root),
+            (false, _) => format!("{} {} {}", rational, sign, root),
+        };
+        match (den.num().is_one(), rational.is_zero()) {
+            (true, _) => write!(f, "{}", num),
+            (false, true) => write!(f, "{}/{}", num, den),
+            (false, false) => write!(f, "({})/{}", num, den),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rat(num: u64, den: u64) -> Rational {
+        Rational::from_natural(
+            true,
+            Natural::from_u64(num),
+            Natural::from_u64(den),
+        )
+    }
+
+    #[test]
+    fn simplify_radicand() {
+        assert_eq!(Surd::sqrt(&rat(2, 1)).unwrap().to_string(), "√2");
+        assert_eq!(Surd::sqrt(&rat(12, 1)).unwrap().to_string(), "2√3");
+        assert_eq!(Surd::sqrt(&rat(1, 2)).unwrap().to_string(), "√2/2");
+        assert_eq!(Surd::sqrt(&-rat(8, 1)).unwrap().to_string(), "2i√2");
+        assert!(Surd::sqrt(&rat(9, 4)).is_none());
+    }
+
+    #[test]
+    fn print_quadratic_roots() {
+        let root = Surd::sqrt(&rat(5, 1)).unwrap();
+        let half = rat(1, 2);
+        let plus = root.affine(&half, &half).unwrap();
+        assert_eq!(plus.to_string(), "(1 + √5)/2");
+        assert_eq!(plus.conjugate().to_string(), "(1 - √5)/2");
+        let minus = root.affine(&-rat(3, 1), &-rat(1, 1)).unwrap();
+        assert_eq!(minus.to_string(), "- 1 - 3√5");
+    }
+}